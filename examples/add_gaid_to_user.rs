@@ -12,7 +12,6 @@
 //!   cargo run --example add_gaid_to_user -- <GAID>
 
 use amp_rs::ApiClient;
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
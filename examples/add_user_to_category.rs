@@ -1,6 +1,5 @@
 use amp_rs::ApiClient;
 use std::env;
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -182,8 +182,8 @@ async fn fetch_asset_data() -> Result<AssetDisplayData, Box<dyn std::error::Erro
 
     // Sort holders to put issuer first, then by balance descending
     holders.sort_by(|a, b| {
-        let a_is_issuer = a.0.as_ref().map(|s| s.as_str()) == Some(issuer_id_str.as_str());
-        let b_is_issuer = b.0.as_ref().map(|s| s.as_str()) == Some(issuer_id_str.as_str());
+        let a_is_issuer = a.0.as_deref() == Some(issuer_id_str.as_str());
+        let b_is_issuer = b.0.as_deref() == Some(issuer_id_str.as_str());
 
         match (a_is_issuer, b_is_issuer) {
             (true, false) => std::cmp::Ordering::Less, // Issuer comes first
@@ -1229,26 +1229,24 @@ fn run_app(
                             app.is_reloading = false;
                         }
                     }
-                    KeyCode::Esc => {
-                        // Esc returns to main screen if in a sub-screen
-                        if app.screen != AppScreen::Main {
-                            app.screen = AppScreen::Main;
+                    // Esc returns to main screen if in a sub-screen
+                    KeyCode::Esc if app.screen != AppScreen::Main => {
+                        app.screen = AppScreen::Main;
 
-                            // Auto-reload data when returning to main screen
-                            app.is_reloading = true;
-                            terminal.draw(|f| ui(f, app))?;
+                        // Auto-reload data when returning to main screen
+                        app.is_reloading = true;
+                        terminal.draw(|f| ui(f, app))?;
 
-                            match rt.block_on(async { fetch_asset_data().await }) {
-                                Ok(new_data) => {
-                                    app.asset_data = new_data;
-                                }
-                                Err(e) => {
-                                    eprintln!("Failed to reload asset data: {}", e);
-                                }
+                        match rt.block_on(async { fetch_asset_data().await }) {
+                            Ok(new_data) => {
+                                app.asset_data = new_data;
+                            }
+                            Err(e) => {
+                                eprintln!("Failed to reload asset data: {}", e);
                             }
-
-                            app.is_reloading = false;
                         }
+
+                        app.is_reloading = false;
                     }
                     KeyCode::Char('d') if app.screen == AppScreen::Main => {
                         app.screen = AppScreen::DistributionInput;
@@ -1330,13 +1328,13 @@ fn run_app(
                             });
                         }
                     }
-                    KeyCode::Char(c) if app.screen == AppScreen::DistributionInput => {
-                        if !c.is_control() {
-                            if app.distribution_input.cursor_pos == 0 {
-                                app.distribution_input.gaid.push(c);
-                            } else {
-                                app.distribution_input.amount.push(c);
-                            }
+                    KeyCode::Char(c)
+                        if app.screen == AppScreen::DistributionInput && !c.is_control() =>
+                    {
+                        if app.distribution_input.cursor_pos == 0 {
+                            app.distribution_input.gaid.push(c);
+                        } else {
+                            app.distribution_input.amount.push(c);
                         }
                     }
                     _ => {}
@@ -1653,7 +1651,7 @@ async fn run_distribution_flow_with_channel(
     };
 
     match client
-        .create_asset_assignments(asset_uuid, &vec![assignment_req])
+        .create_asset_assignments(asset_uuid, &[assignment_req])
         .await
     {
         Ok(assignments) => {
@@ -1705,7 +1703,7 @@ async fn run_distribution_flow_with_channel(
         )
         .await
     {
-        Ok(()) => {
+        Ok(_) => {
             let _ = tx.send(DistributionMessage::Success(
                 "Distribution completed successfully!".to_string(),
             ));
@@ -1745,7 +1743,7 @@ async fn run_distribution_flow_with_channel(
         wallet_name,
         &signer,
     ).await {
-        Ok(()) => {
+        Ok(_) => {
             let _ = tx.send(DistributionMessage::Success("Distribution completed successfully!".to_string()));
             let _ = tx.send(DistributionMessage::Info("Asset has been distributed to the user".to_string()));
         }
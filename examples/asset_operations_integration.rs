@@ -191,7 +191,7 @@ async fn create_environment_signers(
 ) -> Result<HashMap<String, LwkSoftwareSigner>, SignerError> {
     let mut signers = HashMap::new();
 
-    let roles = vec!["issuer", "distributor", "user1", "user2", "treasury"];
+    let roles = ["issuer", "distributor", "user1", "user2", "treasury"];
 
     for (i, role) in roles.iter().enumerate() {
         let (_, signer) = LwkSoftwareSigner::generate_new_indexed(base_index + i)?;
@@ -10,7 +10,6 @@
 //! Make sure to set up your .env file with AMP_USERNAME and AMP_PASSWORD
 
 use amp_rs::ApiClient;
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
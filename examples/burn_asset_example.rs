@@ -34,7 +34,6 @@
 
 use amp_rs::signer::LwkSoftwareSigner;
 use amp_rs::{AmpError, ApiClient, ElementsRpc};
-use dotenvy;
 use std::env;
 
 const BURN_AMOUNT_WHOLE_UNITS: i64 = 1; // 1 whole unit
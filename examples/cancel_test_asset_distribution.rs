@@ -17,7 +17,6 @@
 //! - `AMP_PASSWORD`: AMP API password
 
 use amp_rs::{model::Status, ApiClient};
-use dotenvy;
 use std::env;
 
 #[tokio::main]
@@ -127,10 +126,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let assignments_to_delete: Vec<_> = assignments
             .iter()
             .filter(|a| {
-                let should_delete = a.distribution_uuid.is_some()
-                    || (a.ready_for_distribution && !a.is_distributed)
-                    || !a.is_distributed;
-                should_delete
+                // Ready-but-undistributed assignments are covered by the
+                // plain `!is_distributed` check
+                a.distribution_uuid.is_some() || !a.is_distributed
             })
             .collect();
 
@@ -189,7 +187,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         print!("  Cancelling {}... ", distribution_uuid);
 
         match client
-            .cancel_distribution(asset_uuid, &distribution_uuid)
+            .cancel_distribution(asset_uuid, distribution_uuid)
             .await
         {
             Ok(()) => {
@@ -219,9 +217,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // 1. Linked to distributions (distribution_uuid is Some)
         // 2. Ready for distribution but not yet distributed
         // 3. Not distributed (covers test assignments)
-        let should_delete = assignment.distribution_uuid.is_some()
-            || (assignment.ready_for_distribution && !assignment.is_distributed)
-            || !assignment.is_distributed;
+        // Case 2 is a subset of case 3, so only two checks are needed.
+        let should_delete = assignment.distribution_uuid.is_some() || !assignment.is_distributed;
 
         if should_delete {
             print!("  Deleting assignment {}... ", assignment.id);
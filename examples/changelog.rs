@@ -1,6 +1,5 @@
 use amp_rs::ApiClient;
 use std::fs;
-use tokio;
 
 #[tokio::main]
 async fn main() {
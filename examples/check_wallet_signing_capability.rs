@@ -1,7 +1,6 @@
 //! Diagnostic script to check wallet type and signing capability
 
 use amp_rs::ElementsRpc;
-use dotenvy;
 use std::env;
 
 const WALLET_NAME: &str = "amp_elements_wallet_static_for_funding";
@@ -1,7 +1,6 @@
 //! Clean out all wallets from the cloud Elements node
 
 use amp_rs::ElementsRpc;
-use dotenvy;
 use std::env;
 
 #[tokio::main]
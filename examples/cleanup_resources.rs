@@ -597,9 +597,10 @@ async fn delete_all_registered_users(client: &ApiClient) -> Result<(), Box<dyn s
         }
 
         // Check if the primary GAID is protected
-        let primary_gaid_protected = user.gaid.as_ref().map_or(false, |gaid| {
-            TEST_USER_GAIDS.iter().any(|&protected| protected == gaid)
-        });
+        let primary_gaid_protected = user
+            .gaid
+            .as_ref()
+            .is_some_and(|gaid| TEST_USER_GAIDS.iter().any(|&protected| protected == gaid));
 
         if primary_gaid_protected {
             println!(
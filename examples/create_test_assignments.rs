@@ -4,7 +4,6 @@
 //! the cancellation functionality.
 
 use amp_rs::{model::CreateAssetAssignmentRequest, ApiClient};
-use dotenvy;
 use std::env;
 
 #[tokio::main]
@@ -59,7 +58,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     match client
-        .create_asset_assignments(asset_uuid, &vec![assignment_request])
+        .create_asset_assignments(asset_uuid, &[assignment_request])
         .await
     {
         Ok(assignments) => {
@@ -4,7 +4,6 @@
 //! to demonstrate the cancellation functionality.
 
 use amp_rs::ApiClient;
-use dotenvy;
 use std::env;
 
 #[tokio::main]
@@ -22,8 +22,6 @@
 
 use amp_rs::signer::LwkSoftwareSigner;
 use amp_rs::ElementsRpc;
-use chrono;
-use dotenvy;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
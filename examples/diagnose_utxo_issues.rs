@@ -22,7 +22,6 @@
 //! - `ELEMENTS_RPC_PASSWORD`: Elements RPC password
 
 use amp_rs::ApiClient;
-use dotenvy;
 use serde_json::Value;
 use std::env;
 
@@ -50,7 +49,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // Assets to diagnose
-    let assets_to_check = vec![
+    let assets_to_check = [
         "93cffcb9-c1f5-4873-b5dc-f3ba1f29e3c2", // Previously cleaned asset
         "7750f273-53a9-4984-ad18-d38dd4435207", // Recently checked asset
     ];
@@ -480,7 +479,7 @@ async fn check_treasury_address(
                 if let Some(group_array) = group.as_array() {
                     for addr_info in group_array {
                         if let Some(addr_array) = addr_info.as_array() {
-                            if let Some(address) = addr_array.get(0).and_then(|v| v.as_str()) {
+                            if let Some(address) = addr_array.first().and_then(|v| v.as_str()) {
                                 addresses.push(address.to_string());
                             }
                         }
@@ -102,6 +102,7 @@ async fn main() -> Result<(), AmpError> {
         map_address_amount,
         map_address_asset,
         asset_id: "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d".to_string(),
+        distribution_status: None,
     };
     println!(
         "✓ DistributionResponse struct created: uuid={}, addresses={}",
@@ -35,7 +35,6 @@
 
 use amp_rs::signer::LwkSoftwareSigner;
 use amp_rs::{ApiClient, ElementsRpc};
-use dotenvy;
 use std::env;
 
 /// Asset configuration for this example
@@ -46,6 +45,7 @@ const DEFAULT_USER_GAID: &str = "GAbzSbgCZ6M6WU85rseKTrfehPsjt";
 
 /// Test data structure for asset and user setup
 #[derive(Debug)]
+#[allow(dead_code)]
 struct ExampleSetupData {
     pub asset_uuid: String,
     pub asset_name: String,
@@ -459,7 +459,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Ensure treasury address is configured for asset
     println!("🔧 Ensuring treasury address is configured for asset");
     match api_client
-        .add_asset_treasury_addresses(ASSET_UUID, &vec![treasury_address.clone()])
+        .add_asset_treasury_addresses(ASSET_UUID, std::slice::from_ref(&treasury_address))
         .await
     {
         Ok(_) => println!("✅ Treasury address added to asset (or was already present)"),
@@ -550,9 +550,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .await
     {
-        Ok(()) => {
+        Ok(txids) => {
             let distribution_duration = distribution_start.elapsed();
             println!("🎉 distribute_asset completed successfully!");
+            println!("   - Transactions: {}", txids.join(", "));
             println!("   - Duration: {:?}", distribution_duration);
         }
         Err(e) => {
@@ -19,7 +19,6 @@
 //! - `ELEMENTS_RPC_PASSWORD`: Elements RPC password
 
 use amp_rs::ApiClient;
-use dotenvy;
 use serde_json::Value;
 use std::env;
 
@@ -16,7 +16,6 @@
 //! - `AMP_PASSWORD`: AMP API password
 
 use amp_rs::{model::Status, ApiClient};
-use dotenvy;
 use std::env;
 
 #[tokio::main]
@@ -4,7 +4,6 @@
 //! with a proper import of all private keys and blinding keys.
 
 use amp_rs::ElementsRpc;
-use dotenvy;
 use std::env;
 
 const WALLET_NAME: &str = "amp_elements_wallet_static_for_funding";
@@ -11,7 +11,6 @@
 
 use amp_rs::ApiClient;
 use std::env;
-use tokio;
 
 #[tokio::main]
 async fn main() {
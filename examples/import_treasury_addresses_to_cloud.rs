@@ -4,7 +4,6 @@
 //! Elements wallet so it can see and spend the asset UTXOs.
 
 use amp_rs::{ApiClient, ElementsRpc};
-use dotenvy;
 use std::env;
 
 #[tokio::main]
@@ -18,7 +18,6 @@
 //! ```
 
 use amp_rs::ElementsRpc;
-use dotenvy;
 use std::env;
 
 const WALLET_NAME: &str = "amp_elements_wallet_static_for_funding";
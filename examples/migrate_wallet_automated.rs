@@ -10,7 +10,6 @@
 //! 7. Verifies signing capability
 
 use amp_rs::ElementsRpc;
-use dotenvy;
 use std::env;
 use std::process::Command;
 
@@ -20,7 +20,6 @@
 //! - Asset UUID to register
 
 use amp_rs::ApiClient;
-use dotenvy;
 use std::env;
 
 /// Print usage information
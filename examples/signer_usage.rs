@@ -81,7 +81,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 5: Demonstrate network configuration
     println!("🌐 Example 5: Network configuration verification");
-    let signers = vec![
+    let signers = [
         &signer1,
         &signer2,
         &alice_signer,
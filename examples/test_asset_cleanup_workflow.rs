@@ -30,7 +30,6 @@
 //! - `AMP_PASSWORD`: AMP API password
 
 use amp_rs::ApiClient;
-use dotenvy;
 use std::env;
 
 #[tokio::main]
@@ -38,7 +38,7 @@ use tokio::time::{sleep, Duration, Instant};
 const WALLET_NAME: &str = "amp_elements_wallet_static_for_funding";
 const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(300); // 5 minutes
 const CONFIRMATION_CHECK_INTERVAL: Duration = Duration::from_secs(30); // 30 seconds
-const MAX_ASSET_AMOUNT: i64 = 21_000_000_00000000; // 21 million with 8 decimal precision
+const MAX_ASSET_AMOUNT: i64 = 2_100_000_000_000_000; // 21 million with 8 decimal precision
 
 #[tokio::main]
 async fn main() -> Result<(), AmpError> {
@@ -10,7 +10,6 @@
 
 use amp_rs::ApiClient;
 use std::env;
-use tokio;
 
 #[tokio::main]
 async fn main() {
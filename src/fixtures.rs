@@ -0,0 +1,147 @@
+//! Sample AMP and Elements payloads for serde regression tests
+//!
+//! Enabled with the `fixtures` feature. Each constant is a realistic response
+//! body for one model, with every field the model serializes present, so
+//! [`assert_round_trip`] can compare it field for field. Floats are written
+//! with a fractional part (`100.0`), matching how the models serialize them.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// An asset as returned by `GET /assets/{uuid}`, deserializes to [`crate::model::Asset`]
+pub const ASSET_JSON: &str = r#"{
+    "name": "Test Security",
+    "asset_uuid": "8f5b4c6e-2d1a-4b3c-9e7f-0a1b2c3d4e5f",
+    "issuer": 1,
+    "asset_id": "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189",
+    "reissuance_token_id": "b2e15d0d7a0c94e4e2ce0fe6e8691b9e451377f6e46e8045a86f7c4b5d4f0f23",
+    "requirements": [3, 7],
+    "ticker": "TSEC",
+    "precision": 0,
+    "domain": "example.com",
+    "pubkey": "02d9fae6d1b1e2e2e4c1f7e1c6bb5a3e8d8f8f3c9e5a1a4b1f7e2d3c4b5a69788",
+    "is_registered": true,
+    "is_authorized": true,
+    "is_locked": false,
+    "issuer_authorization_endpoint": null,
+    "transfer_restricted": true
+}"#;
+
+/// A distribution as returned by `GET /assets/{uuid}/distributions/{uuid}`,
+/// deserializes to [`crate::model::DistributionResponse`]
+pub const DISTRIBUTION_RESPONSE_JSON: &str = r#"{
+    "distribution_uuid": "5e7c9a1b-3d2f-4e6a-8b0c-1d2e3f4a5b6c",
+    "map_address_amount": {
+        "tlq1qqfttx29npj9ltqu7yszcw3u8jsytmvmzg8wfctnuvx065y4jjgyk0g7xk8hy5jwe72hnkwqzjar5f7afysty56vff0m9nk7h5": 100.0
+    },
+    "map_address_asset": {
+        "tlq1qqfttx29npj9ltqu7yszcw3u8jsytmvmzg8wfctnuvx065y4jjgyk0g7xk8hy5jwe72hnkwqzjar5f7afysty56vff0m9nk7h5": "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189"
+    },
+    "asset_id": "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189",
+    "distribution_status": "UNCONFIRMED"
+}"#;
+
+/// A `listunspent` entry for a confidential output, deserializes to [`crate::model::Unspent`]
+pub const UNSPENT_JSON: &str = r#"{
+    "txid": "332beb69994435786697ccd4c0082f13cabad1c90e8afb53648e7e23e6944798",
+    "vout": 1,
+    "amount": 150.0,
+    "asset": "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189",
+    "address": "tex1q50rtrmj2f8vl9tem8qpfw36ylw5jg9j24kmnvl",
+    "spendable": true,
+    "confirmations": 6,
+    "scriptpubkey": "0014a3c6b1ee4a49d9f2af3b380297474fbba9241655",
+    "amountblinder": "1f3a5c7e9b2d4f6a8c0e2b4d6f8a1c3e5a7c9e1b3d5f7a9c2e4b6d8f0a1c3e5a",
+    "assetblinder": "9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a2f1e0d9c8b7a6f5e4d3c2b1a0f9e8d"
+}"#;
+
+/// A wallet `gettransaction` response, deserializes to [`crate::model::TransactionDetail`]
+pub const TRANSACTION_DETAIL_JSON: &str = r#"{
+    "txid": "332beb69994435786697ccd4c0082f13cabad1c90e8afb53648e7e23e6944798",
+    "confirmations": 3,
+    "blockheight": 1234567,
+    "hex": "0200000000010123456789abcdef00000000000000000002",
+    "blockhash": "00000000000000000001a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f7",
+    "blocktime": 1760000000,
+    "time": 1759999940,
+    "timereceived": 1759999940,
+    "details": [
+        {
+            "address": "tex1q50rtrmj2f8vl9tem8qpfw36ylw5jg9j24kmnvl",
+            "category": "send",
+            "amount": -100.0,
+            "vout": 0
+        }
+    ]
+}"#;
+
+/// Deserializes `json` into `T`, serializes it back and asserts nothing changed
+///
+/// Returns the deserialized value for further assertions. Comparison is on
+/// parsed JSON values, so whitespace and key order do not matter, but a field
+/// that is dropped, renamed or re-typed does.
+///
+/// # Panics
+/// Panics if `json` does not deserialize into `T` or the re-serialized value
+/// differs from the input
+///
+/// # Examples
+/// ```
+/// # use amp_rs::fixtures::{assert_round_trip, UNSPENT_JSON};
+/// let utxo: amp_rs::Unspent = assert_round_trip(UNSPENT_JSON);
+/// assert!(utxo.spendable);
+/// ```
+#[must_use = "the deserialized value is returned for further assertions"]
+pub fn assert_round_trip<T: DeserializeOwned + Serialize>(json: &str) -> T {
+    let expected: serde_json::Value =
+        serde_json::from_str(json).expect("fixture is not valid JSON");
+    let value: T = serde_json::from_value(expected.clone()).unwrap_or_else(|e| {
+        panic!(
+            "fixture does not deserialize into {}: {e}",
+            std::any::type_name::<T>()
+        )
+    });
+    let actual = serde_json::to_value(&value).expect("value does not serialize");
+    assert_eq!(
+        actual,
+        expected,
+        "{} did not round-trip",
+        std::any::type_name::<T>()
+    );
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        Asset, DistributionResponse, DistributionStatus, TransactionDetail, Unspent,
+    };
+
+    #[test]
+    fn test_core_models_round_trip() {
+        let asset: Asset = assert_round_trip(ASSET_JSON);
+        assert_eq!(asset.requirements, vec![3, 7]);
+        assert!(asset.issuer_authorization_endpoint.is_none());
+
+        let distribution: DistributionResponse = assert_round_trip(DISTRIBUTION_RESPONSE_JSON);
+        assert_eq!(distribution.status(), DistributionStatus::Unconfirmed);
+        assert_eq!(distribution.map_address_amount.len(), 1);
+
+        let utxo: Unspent = assert_round_trip(UNSPENT_JSON);
+        assert_eq!(utxo.confirmations, Some(6));
+        assert!(utxo.redeemscript.is_none());
+
+        let tx: TransactionDetail = assert_round_trip(TRANSACTION_DETAIL_JSON);
+        assert_eq!(tx.confirmations, 3);
+        assert_eq!(tx.details.map(|details| details.len()), Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "did not round-trip")]
+    fn test_assert_round_trip_detects_dropped_fields() {
+        // `extra` is not a field of Unspent, so serializing loses it
+        let json = UNSPENT_JSON.replacen('{', r#"{"extra": 1,"#, 1);
+        let _: Unspent = assert_round_trip(&json);
+    }
+}
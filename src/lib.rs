@@ -10,6 +10,8 @@
 //! - [`mocks`] - Mock implementations for testing
 //! - [`mock_client`] - Mock API client for integration testing
 //! - [`signer`] - Transaction signing implementations ⚠️ **TESTNET ONLY**
+//! - `testkit` - Regtest fixtures for integration tests (requires the `testkit` feature)
+//! - `fixtures` - Sample model payloads for serde regression tests (requires the `fixtures` feature)
 //!
 //! ## Signer Security Warning
 //!
@@ -29,19 +31,34 @@
 
 pub mod client;
 pub mod client_trait;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
 pub mod mock_client;
 pub mod mocks;
 pub mod model;
 pub mod signer;
+#[cfg(feature = "testkit")]
+pub mod testkit;
 
-pub use client::{AmpError, ApiClient, ElementsRpc, Error};
+pub use client::{
+    analyze_distribution_privacy, system_health, total_supply_held, AddressImportReport,
+    AddressReissuance, AmountCheck, AmpError, ApiClient, AssetHolders, BroadcastRejectReason,
+    ClassifiedOutput, ComponentHealth, ConfirmationPolicy, ConfirmedOperation, DescriptorTimestamp,
+    DistributionDiagnostics, DistributionMethod, ElementsRpc, Endpoint, Error, LatencyStats,
+    MempoolEntry, MempoolInfo, OperationContext, OutputClassification, OutputKind,
+    OutputVerification, PasswordRotationStep, PendingDistribution, PendingOutput, PrivacyAnalysis,
+    PrivacyWarning, ReissuanceToken, RpcErrorAction, RpcErrorPolicy, SmartFeeEstimate,
+    SoftForkInfo, SoftForks, SupplyReport, SystemHealth, UserRegistrationReport, UtxoLockGuard,
+    WalletUnspentReport, STREAMED_RPC_BODY_THRESHOLD, TRANSIENT_RPC_CODES,
+};
 pub use client_trait::AmpClient;
-pub use mock_client::MockApiClient;
+pub use mock_client::{MockApiClient, RecordedCall};
 pub use model::{
-    AssetDistributionAssignment, AssetTransaction, AssetTransactionInput, AssetTransactionOutput,
-    AssetTransactionParams, BurnConfirmRequest, BurnCreate, BurnRequest, BurnResponse,
-    ConfirmDistributionRequest, DistributionResponse, DistributionTxData, ReceivedByAddress,
-    Reissuance, ReissueConfirmRequest, ReissueRequest, ReissueRequestResponse, ReissueResponse,
-    TransactionDetail, TxInput, Unspent, UpdateBlindersRequest,
+    AddressClass, AddressType, AssetDistributionAssignment, AssetTransaction,
+    AssetTransactionInput, AssetTransactionOutput, AssetTransactionParams, BurnConfirmRequest,
+    BurnCreate, BurnRequest, BurnResponse, ConfirmDistributionRequest, DistributionResponse,
+    DistributionTxData, DistributionTxInput, ElementsNetwork, ElementsOutput, ReceivedByAddress,
+    Reissuance, ReissuanceState, ReissueConfirmRequest, ReissueRequest, ReissueRequestResponse,
+    ReissueResponse, TransactionDetail, TxInput, Unspent, UpdateBlindersRequest,
 };
 pub use signer::{LwkSoftwareSigner, Signer, SignerError};
@@ -92,6 +92,18 @@ use crate::model::{
     RegisterAssetResponse, RegisteredUserResponse, Reissuance, ValidateGaidResponse,
 };
 
+/// A call made against a [`MockApiClient`].
+///
+/// `endpoint` is the name of the `ApiClient` method that was invoked (for example
+/// `"create_distribution"`), and `body` holds the request payload for methods that send one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedCall {
+    /// Name of the `ApiClient` method that was invoked
+    pub endpoint: String,
+    /// Request payload, or `None` for methods that send no body
+    pub body: Option<serde_json::Value>,
+}
+
 /// Mock API Client that provides the same interface as ApiClient
 /// but returns configurable mock responses.
 #[derive(Debug, Clone)]
@@ -116,6 +128,7 @@ struct MockApiClientInner {
     asset_activities: Mutex<HashMap<String, Vec<Activity>>>,
     distributions: Mutex<HashMap<String, Distribution>>,
     managers: Mutex<HashMap<i64, crate::model::Manager>>,
+    calls: Mutex<Vec<RecordedCall>>,
     next_user_id: AtomicI64,
     next_category_id: AtomicI64,
     next_asset_uuid: AtomicU64,
@@ -156,6 +169,7 @@ impl MockApiClient {
             asset_activities: Mutex::new(HashMap::new()),
             distributions: Mutex::new(HashMap::new()),
             managers: Mutex::new(HashMap::new()),
+            calls: Mutex::new(Vec::new()),
             next_user_id: AtomicI64::new(1),
             next_category_id: AtomicI64::new(1),
             next_asset_uuid: AtomicU64::new(1),
@@ -496,34 +510,127 @@ impl MockApiClient {
     // Token methods to match ApiClient interface
     /// Gets a mock authentication token
     pub async fn get_token(&self) -> Result<String, Error> {
+        self.record("get_token", None);
         Ok("mock_token".to_string())
     }
 
     /// Gets token info (always None for mock)
     pub async fn get_token_info(&self) -> Result<Option<crate::model::TokenInfo>, Error> {
+        self.record("get_token_info", None);
         Ok(None)
     }
 
     /// Clears token (no-op for mock)
     pub async fn clear_token(&self) -> Result<(), Error> {
+        self.record("clear_token", None);
         Ok(())
     }
 
     /// Force refresh (returns mock token)
     pub async fn force_refresh(&self) -> Result<String, Error> {
+        self.record("force_refresh", None);
         Ok("mock_token".to_string())
     }
 
+    // Call recording
+
+    /// Records a call to `endpoint`
+    fn record(&self, endpoint: &str, body: Option<serde_json::Value>) {
+        self.inner.calls.lock().unwrap().push(RecordedCall {
+            endpoint: endpoint.to_string(),
+            body,
+        });
+    }
+
+    /// Returns every call made against this client, in order.
+    ///
+    /// Clones of a `MockApiClient` share the same call log.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use amp_rs::MockApiClient;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = MockApiClient::new();
+    /// client.get_assets().await.unwrap();
+    ///
+    /// let calls = client.recorded_calls();
+    /// assert_eq!(calls.len(), 1);
+    /// assert_eq!(calls[0].endpoint, "get_assets");
+    /// # }
+    /// ```
+    pub fn recorded_calls(&self) -> Vec<RecordedCall> {
+        self.inner.calls.lock().unwrap().clone()
+    }
+
+    /// Returns the recorded calls to `endpoint`, in order
+    pub fn recorded_calls_to(&self, endpoint: &str) -> Vec<RecordedCall> {
+        self.inner
+            .calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|call| call.endpoint == endpoint)
+            .cloned()
+            .collect()
+    }
+
+    /// Asserts that `endpoint` was called exactly `times` times.
+    ///
+    /// # Panics
+    /// Panics with the full call log if the count does not match.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use amp_rs::MockApiClient;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = MockApiClient::new();
+    /// client.get_assets().await.unwrap();
+    ///
+    /// client.expect_called("get_assets", 1);
+    /// client.expect_called("get_asset", 0);
+    /// # }
+    /// ```
+    pub fn expect_called(&self, endpoint: &str, times: usize) {
+        let calls = self.recorded_calls();
+        let actual = calls
+            .iter()
+            .filter(|call| call.endpoint == endpoint)
+            .count();
+        assert_eq!(
+            actual,
+            times,
+            "expected `{}` to be called {} time(s) but it was called {} time(s); recorded calls: {:?}",
+            endpoint,
+            times,
+            actual,
+            calls.iter().map(|call| &call.endpoint).collect::<Vec<_>>()
+        );
+    }
+
+    /// Clears the call log
+    pub fn clear_recorded_calls(&self) {
+        self.inner.calls.lock().unwrap().clear();
+    }
+
     // Asset methods
 
     /// Gets all assets
     pub async fn get_assets(&self) -> Result<Vec<Asset>, Error> {
+        self.record("get_assets", None);
         let assets = self.inner.assets.lock().unwrap();
         Ok(assets.values().cloned().collect())
     }
 
     /// Gets a specific asset by UUID
     pub async fn get_asset(&self, asset_uuid: &str) -> Result<Asset, Error> {
+        self.record("get_asset", None);
+        self.find_asset(asset_uuid)
+    }
+
+    /// Looks up an asset without recording a call
+    fn find_asset(&self, asset_uuid: &str) -> Result<Asset, Error> {
         let assets = self.inner.assets.lock().unwrap();
         assets
             .get(asset_uuid)
@@ -533,6 +640,7 @@ impl MockApiClient {
 
     /// Issues a new asset
     pub async fn issue_asset(&self, request: &IssuanceRequest) -> Result<IssuanceResponse, Error> {
+        self.record("issue_asset", serde_json::to_value(request).ok());
         let next_id = self.inner.next_asset_uuid.fetch_add(1, Ordering::SeqCst);
         let asset_uuid = format!(
             "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
@@ -637,8 +745,9 @@ impl MockApiClient {
     pub async fn edit_asset(
         &self,
         asset_uuid: &str,
-        _request: &EditAssetRequest,
+        request: &EditAssetRequest,
     ) -> Result<Asset, Error> {
+        self.record("edit_asset", serde_json::to_value(request).ok());
         let mut assets = self.inner.assets.lock().unwrap();
         assets
             .get_mut(asset_uuid)
@@ -652,6 +761,7 @@ impl MockApiClient {
 
     /// Registers an asset
     pub async fn register_asset(&self, asset_uuid: &str) -> Result<RegisterAssetResponse, Error> {
+        self.record("register_asset", None);
         let mut assets = self.inner.assets.lock().unwrap();
         let asset = assets
             .get_mut(asset_uuid)
@@ -668,6 +778,7 @@ impl MockApiClient {
 
     /// Deletes an asset
     pub async fn delete_asset(&self, asset_uuid: &str) -> Result<(), Error> {
+        self.record("delete_asset", None);
         let mut assets = self.inner.assets.lock().unwrap();
         assets
             .remove(asset_uuid)
@@ -677,6 +788,7 @@ impl MockApiClient {
 
     /// Locks an asset
     pub async fn lock_asset(&self, asset_uuid: &str) -> Result<Asset, Error> {
+        self.record("lock_asset", None);
         let mut assets = self.inner.assets.lock().unwrap();
         let asset = assets
             .get_mut(asset_uuid)
@@ -688,6 +800,7 @@ impl MockApiClient {
 
     /// Unlocks an asset
     pub async fn unlock_asset(&self, asset_uuid: &str) -> Result<Asset, Error> {
+        self.record("unlock_asset", None);
         let mut assets = self.inner.assets.lock().unwrap();
         let asset = assets
             .get_mut(asset_uuid)
@@ -699,6 +812,7 @@ impl MockApiClient {
 
     /// Gets asset summary
     pub async fn get_asset_summary(&self, asset_uuid: &str) -> Result<AssetSummary, Error> {
+        self.record("get_asset_summary", None);
         let summaries = self.inner.asset_summaries.lock().unwrap();
         summaries
             .get(asset_uuid)
@@ -721,6 +835,7 @@ impl MockApiClient {
 
     /// Gets asset balance
     pub async fn get_asset_balance(&self, _asset_uuid: &str) -> Result<Balance, Error> {
+        self.record("get_asset_balance", None);
         Ok(vec![]) // Empty balance by default
     }
 
@@ -751,6 +866,7 @@ impl MockApiClient {
         asset_uuid: &str,
         _height: Option<i64>,
     ) -> Result<Vec<Ownership>, Error> {
+        self.record("get_asset_ownerships", None);
         let ownerships = self.inner.asset_ownerships.lock().unwrap();
         Ok(ownerships.get(asset_uuid).cloned().unwrap_or_default())
     }
@@ -794,17 +910,20 @@ impl MockApiClient {
         asset_uuid: &str,
         _params: &AssetActivityParams,
     ) -> Result<Vec<Activity>, Error> {
+        self.record("get_asset_activities", None);
         let activities = self.inner.asset_activities.lock().unwrap();
         Ok(activities.get(asset_uuid).cloned().unwrap_or_default())
     }
 
     /// Gets asset memo
     pub async fn get_asset_memo(&self, _asset_uuid: &str) -> Result<String, Error> {
+        self.record("get_asset_memo", None);
         Ok("Mock asset memo".to_string())
     }
 
     /// Sets asset memo
-    pub async fn set_asset_memo(&self, _asset_uuid: &str, _memo: &str) -> Result<(), Error> {
+    pub async fn set_asset_memo(&self, _asset_uuid: &str, memo: &str) -> Result<(), Error> {
+        self.record("set_asset_memo", Some(serde_json::json!(memo)));
         Ok(())
     }
 
@@ -812,6 +931,7 @@ impl MockApiClient {
 
     /// Gets all registered users
     pub async fn get_registered_users(&self) -> Result<Vec<RegisteredUserResponse>, Error> {
+        self.record("get_registered_users", None);
         let users = self.inner.users.lock().unwrap();
         Ok(users
             .values()
@@ -828,6 +948,7 @@ impl MockApiClient {
 
     /// Gets a specific registered user by ID
     pub async fn get_registered_user(&self, user_id: i64) -> Result<RegisteredUserResponse, Error> {
+        self.record("get_registered_user", None);
         let users = self.inner.users.lock().unwrap();
         users
             .get(&user_id)
@@ -847,6 +968,7 @@ impl MockApiClient {
         &self,
         request: &crate::model::RegisteredUserAdd,
     ) -> Result<RegisteredUserResponse, Error> {
+        self.record("add_registered_user", serde_json::to_value(request).ok());
         let user_id = self.inner.next_user_id.fetch_add(1, Ordering::SeqCst);
         let user = RegisteredUserResponse {
             id: user_id,
@@ -888,6 +1010,7 @@ impl MockApiClient {
 
     /// Deletes a registered user
     pub async fn delete_registered_user(&self, user_id: i64) -> Result<(), Error> {
+        self.record("delete_registered_user", None);
         let mut users = self.inner.users.lock().unwrap();
         users
             .remove(&user_id)
@@ -901,6 +1024,7 @@ impl MockApiClient {
         user_id: i64,
         request: &crate::model::RegisteredUserEdit,
     ) -> Result<RegisteredUserResponse, Error> {
+        self.record("edit_registered_user", serde_json::to_value(request).ok());
         let mut users = self.inner.users.lock().unwrap();
         let user = users
             .get_mut(&user_id)
@@ -922,6 +1046,7 @@ impl MockApiClient {
 
     /// Gets GAIDs for a registered user
     pub async fn get_registered_user_gaids(&self, user_id: i64) -> Result<Vec<String>, Error> {
+        self.record("get_registered_user_gaids", None);
         let user_gaids = self.inner.user_gaids.lock().unwrap();
         Ok(user_gaids.get(&user_id).cloned().unwrap_or_default())
     }
@@ -932,6 +1057,7 @@ impl MockApiClient {
         user_id: i64,
         request: &crate::model::GaidRequest,
     ) -> Result<(), Error> {
+        self.record("add_gaid_to_registered_user", None);
         let _users = self.inner.users.lock().unwrap();
         if !_users.contains_key(&user_id) {
             return Err(Error::RequestFailed(format!("User not found: {}", user_id)));
@@ -954,6 +1080,7 @@ impl MockApiClient {
 
     /// Validates a GAID
     pub async fn validate_gaid(&self, gaid: &str) -> Result<ValidateGaidResponse, Error> {
+        self.record("validate_gaid", None);
         let validations = self.inner.gaid_validations.lock().unwrap();
         let is_valid = validations.get(gaid).copied().unwrap_or(false);
         Ok(ValidateGaidResponse {
@@ -963,11 +1090,13 @@ impl MockApiClient {
             } else {
                 Some("Invalid GAID".to_string())
             },
+            error_code: None,
         })
     }
 
     /// Gets the address for a GAID
     pub async fn get_gaid_address(&self, gaid: &str) -> Result<AddressGaidResponse, Error> {
+        self.record("get_gaid_address", None);
         let addresses = self.inner.gaid_addresses.lock().unwrap();
         let address = addresses.get(gaid).cloned().unwrap_or_else(|| {
             "vjU2i2EM2viGEzSywpStMPkTX9U9QSDsLSN63kJJYVpxKJZuxaph8v5r5Jf11aqnfBVdjSbrvcJ2pw26"
@@ -982,6 +1111,7 @@ impl MockApiClient {
 
     /// Gets the balance for a GAID
     pub async fn get_gaid_balance(&self, gaid: &str) -> Result<Balance, Error> {
+        self.record("get_gaid_balance", None);
         let balances = self.inner.gaid_balances.lock().unwrap();
         Ok(balances
             .get(gaid)
@@ -1004,6 +1134,7 @@ impl MockApiClient {
         gaid: &str,
         asset_uuid: &str,
     ) -> Result<GaidBalanceEntry, Error> {
+        self.record("get_gaid_asset_balance", None);
         let balances = self.inner.gaid_balances.lock().unwrap();
         let balance = balances
             .get(gaid)
@@ -1032,6 +1163,7 @@ impl MockApiClient {
         &self,
         gaid: &str,
     ) -> Result<RegisteredUserResponse, Error> {
+        self.record("get_gaid_registered_user", None);
         let user_gaids = self.inner.user_gaids.lock().unwrap();
         let users = self.inner.users.lock().unwrap();
 
@@ -1058,6 +1190,7 @@ impl MockApiClient {
 
     /// Gets all categories
     pub async fn get_categories(&self) -> Result<Vec<CategoryResponse>, Error> {
+        self.record("get_categories", None);
         let categories = self.inner.categories.lock().unwrap();
         Ok(categories
             .values()
@@ -1073,6 +1206,7 @@ impl MockApiClient {
 
     /// Gets a specific category by ID
     pub async fn get_category(&self, category_id: i64) -> Result<CategoryResponse, Error> {
+        self.record("get_category", None);
         let categories = self.inner.categories.lock().unwrap();
         categories
             .get(&category_id)
@@ -1091,6 +1225,7 @@ impl MockApiClient {
         &self,
         request: &crate::model::CategoryAdd,
     ) -> Result<CategoryResponse, Error> {
+        self.record("add_category", serde_json::to_value(request).ok());
         let category_id = self.inner.next_category_id.fetch_add(1, Ordering::SeqCst);
         let category = CategoryResponse {
             id: category_id,
@@ -1127,6 +1262,7 @@ impl MockApiClient {
         category_id: i64,
         request: &crate::model::CategoryEdit,
     ) -> Result<CategoryResponse, Error> {
+        self.record("edit_category", serde_json::to_value(request).ok());
         let mut categories = self.inner.categories.lock().unwrap();
         let category = categories
             .get_mut(&category_id)
@@ -1150,6 +1286,7 @@ impl MockApiClient {
 
     /// Deletes a category
     pub async fn delete_category(&self, category_id: i64) -> Result<(), Error> {
+        self.record("delete_category", None);
         let mut categories = self.inner.categories.lock().unwrap();
         categories
             .remove(&category_id)
@@ -1163,6 +1300,7 @@ impl MockApiClient {
         category_id: i64,
         user_id: i64,
     ) -> Result<(), Error> {
+        self.record("add_registered_user_to_category", None);
         let mut categories = self.inner.categories.lock().unwrap();
         let category = categories
             .get_mut(&category_id)
@@ -1180,6 +1318,7 @@ impl MockApiClient {
         category_id: i64,
         user_id: i64,
     ) -> Result<(), Error> {
+        self.record("remove_registered_user_from_category", None);
         let mut categories = self.inner.categories.lock().unwrap();
         let category = categories
             .get_mut(&category_id)
@@ -1195,6 +1334,7 @@ impl MockApiClient {
         category_id: i64,
         asset_uuid: &str,
     ) -> Result<CategoryResponse, Error> {
+        self.record("add_asset_to_category", None);
         let mut categories = self.inner.categories.lock().unwrap();
         let category = categories
             .get_mut(&category_id)
@@ -1218,6 +1358,7 @@ impl MockApiClient {
         category_id: i64,
         asset_uuid: &str,
     ) -> Result<CategoryResponse, Error> {
+        self.record("remove_asset_from_category", None);
         let mut categories = self.inner.categories.lock().unwrap();
         let category = categories
             .get_mut(&category_id)
@@ -1237,6 +1378,7 @@ impl MockApiClient {
 
     /// Gets all assignments for an asset
     pub async fn get_asset_assignments(&self, asset_uuid: &str) -> Result<Vec<Assignment>, Error> {
+        self.record("get_asset_assignments", None);
         let assignments = self.inner.asset_assignments.lock().unwrap();
         Ok(assignments
             .get(asset_uuid)
@@ -1268,6 +1410,7 @@ impl MockApiClient {
         asset_uuid: &str,
         assignment_id: &str,
     ) -> Result<Assignment, Error> {
+        self.record("get_asset_assignment", None);
         let assignments = self.inner.asset_assignments.lock().unwrap();
         let assignment_id_num = assignment_id.parse::<i64>().map_err(|_| {
             Error::RequestFailed(format!("Invalid assignment ID: {}", assignment_id))
@@ -1304,8 +1447,12 @@ impl MockApiClient {
         asset_uuid: &str,
         requests: &[CreateAssetAssignmentRequest],
     ) -> Result<Vec<Assignment>, Error> {
+        self.record(
+            "create_asset_assignments",
+            Some(serde_json::json!({ "assignments": requests })),
+        );
         // Verify asset exists
-        let _asset = self.get_asset(asset_uuid).await?;
+        let _asset = self.find_asset(asset_uuid)?;
 
         let mut assignments_map = self.inner.asset_assignments.lock().unwrap();
         let assignments = assignments_map.entry(asset_uuid.to_string()).or_default();
@@ -1378,13 +1525,16 @@ impl MockApiClient {
         asset_uuid: &str,
         assignments: Vec<crate::model::AssetDistributionAssignment>,
     ) -> Result<crate::model::DistributionResponse, AmpError> {
+        self.record(
+            "create_distribution",
+            Some(serde_json::json!({ "assignments": assignments })),
+        );
         use crate::model::DistributionResponse;
         use AmpError;
 
         // Verify asset exists
         let asset = self
-            .get_asset(asset_uuid)
-            .await
+            .find_asset(asset_uuid)
             .map_err(|e| AmpError::api(format!("Asset not found: {}", e)))?;
 
         if assignments.is_empty() {
@@ -1417,6 +1567,7 @@ impl MockApiClient {
             map_address_amount,
             map_address_asset,
             asset_id: asset.asset_id,
+            distribution_status: None,
         })
     }
 
@@ -1425,9 +1576,13 @@ impl MockApiClient {
         &self,
         _asset_uuid: &str,
         distribution_uuid: &str,
-        _tx_data: crate::model::AmpTxData,
-        _change_data: Vec<crate::model::Unspent>,
+        tx_data: crate::model::AmpTxData,
+        change_data: Vec<crate::model::Unspent>,
     ) -> Result<(), AmpError> {
+        self.record(
+            "confirm_distribution",
+            Some(serde_json::json!({ "tx_data": tx_data, "change_data": change_data })),
+        );
         // Store distribution
         let mut distributions = self.inner.distributions.lock().unwrap();
         // Create a basic distribution record
@@ -1456,6 +1611,7 @@ impl MockApiClient {
         &self,
         _asset_uuid: &str,
     ) -> Result<Vec<Distribution>, AmpError> {
+        self.record("get_asset_distributions", None);
         // For now, return empty list - can be extended to track distributions per asset
         Ok(vec![])
     }
@@ -1466,6 +1622,7 @@ impl MockApiClient {
         _asset_uuid: &str,
         distribution_uuid: &str,
     ) -> Result<Distribution, AmpError> {
+        self.record("get_asset_distribution", None);
         let distributions = self.inner.distributions.lock().unwrap();
         distributions
             .get(distribution_uuid)
@@ -1509,11 +1666,11 @@ impl MockApiClient {
         asset_uuid: &str,
         request: &crate::model::ReissueRequest,
     ) -> Result<crate::model::ReissueRequestResponse, AmpError> {
+        self.record("reissue_request", serde_json::to_value(request).ok());
         use crate::model::{Outpoint, ReissueRequestResponse};
 
         let asset = self
-            .get_asset(asset_uuid)
-            .await
+            .find_asset(asset_uuid)
             .map_err(|e| AmpError::api(format!("Asset not found: {}", e)))?;
 
         if asset.reissuance_token_id.is_none() {
@@ -1540,6 +1697,7 @@ impl MockApiClient {
         asset_uuid: &str,
         _request: &crate::model::ReissueConfirmRequest,
     ) -> Result<crate::model::ReissueResponse, AmpError> {
+        self.record("reissue_confirm", None);
         use crate::model::ReissueResponse;
 
         // Update asset summary
@@ -1560,12 +1718,12 @@ impl MockApiClient {
         &self,
         asset_uuid: &str,
     ) -> Result<Vec<crate::model::Reissuance>, AmpError> {
+        self.record("get_asset_reissuances", None);
         use crate::model::Reissuance;
 
         // Check if asset exists
         let _asset = self
-            .get_asset(asset_uuid)
-            .await
+            .find_asset(asset_uuid)
             .map_err(|e| AmpError::api(format!("Asset not found: {}", e)))?;
 
         // Check if asset has been reissued by looking at summary
@@ -1625,10 +1783,11 @@ impl MockApiClient {
         asset_uuid: &str,
         params: &crate::model::AssetTransactionParams,
     ) -> Result<Vec<crate::model::AssetTransaction>, Error> {
+        self.record("get_asset_transactions", None);
         use crate::model::{AssetTransaction, AssetTransactionOutput};
 
         // Check if asset exists
-        let asset = self.get_asset(asset_uuid).await?;
+        let asset = self.find_asset(asset_uuid)?;
 
         // Get any stored transactions for this asset
         let transactions = self.inner.asset_transactions.lock().unwrap();
@@ -1722,10 +1881,11 @@ impl MockApiClient {
         asset_uuid: &str,
         txid: &str,
     ) -> Result<crate::model::AssetTransaction, Error> {
+        self.record("get_asset_transaction", None);
         use crate::model::{AssetTransaction, AssetTransactionOutput};
 
         // Check if asset exists
-        let asset = self.get_asset(asset_uuid).await?;
+        let asset = self.find_asset(asset_uuid)?;
 
         // Look for the transaction in stored transactions
         let transactions = self.inner.asset_transactions.lock().unwrap();
@@ -1823,10 +1983,11 @@ impl MockApiClient {
         &self,
         asset_uuid: &str,
     ) -> Result<crate::model::AssetLostOutputs, Error> {
+        self.record("get_asset_lost_outputs", None);
         use crate::model::AssetLostOutputs;
 
         // Check if asset exists
-        let _ = self.get_asset(asset_uuid).await?;
+        let _ = self.find_asset(asset_uuid)?;
 
         // Check if lost outputs are configured
         let lost_outputs_map = self.inner.asset_lost_outputs.lock().unwrap();
@@ -1895,8 +2056,9 @@ impl MockApiClient {
         asset_uuid: &str,
         _request: &crate::model::UpdateBlindersRequest,
     ) -> Result<(), Error> {
+        self.record("update_asset_blinders", None);
         // Check if asset exists
-        let _ = self.get_asset(asset_uuid).await?;
+        let _ = self.find_asset(asset_uuid)?;
 
         // In a real implementation, this would update the blinder information
         // For the mock, we just verify the asset exists and return success
@@ -1940,6 +2102,7 @@ impl MockApiClient {
         manager_id: i64,
         password: secrecy::Secret<String>,
     ) -> Result<crate::model::ChangePasswordResponse, Error> {
+        self.record("change_manager_password", None);
         use crate::model::{ChangePasswordResponse, Password};
 
         // Check if manager exists
@@ -1981,6 +2144,10 @@ impl MockApiClient {
         asset_uuid: &str,
         amount: i64,
     ) -> Result<crate::model::BurnCreate, AmpError> {
+        self.record(
+            "burn_request",
+            Some(serde_json::json!({ "amount": amount })),
+        );
         use crate::model::{BurnCreate, Outpoint};
 
         if amount <= 0 {
@@ -1988,8 +2155,7 @@ impl MockApiClient {
         }
 
         let asset = self
-            .get_asset(asset_uuid)
-            .await
+            .find_asset(asset_uuid)
             .map_err(|e| AmpError::api(format!("Asset not found: {}", e)))?;
 
         Ok(BurnCreate {
@@ -2012,6 +2178,7 @@ impl MockApiClient {
         asset_uuid: &str,
         _request: &crate::model::BurnConfirmRequest,
     ) -> Result<crate::model::BurnResponse, AmpError> {
+        self.record("burn_confirm", None);
         // Update asset summary
         let mut summaries = self.inner.asset_summaries.lock().unwrap();
         if let Some(summary) = summaries.get_mut(asset_uuid) {
@@ -2028,6 +2195,10 @@ impl MockApiClient {
 
     /// Broadcasts a transaction
     pub async fn broadcast_transaction(&self, tx_hex: &str) -> Result<BroadcastResponse, Error> {
+        self.record(
+            "broadcast_transaction",
+            Some(serde_json::json!({ "tx_hex": tx_hex })),
+        );
         Ok(BroadcastResponse {
             txid: format!("{:064x}", tx_hex.len() as u64),
             hex: tx_hex.to_string(),
@@ -2036,6 +2207,7 @@ impl MockApiClient {
 
     /// Gets broadcast status
     pub async fn get_broadcast_status(&self, txid: &str) -> Result<BroadcastResponse, Error> {
+        self.record("get_broadcast_status", None);
         Ok(BroadcastResponse {
             txid: txid.to_string(),
             hex: format!("mock_hex_for_{}", txid),
@@ -2046,6 +2218,7 @@ impl MockApiClient {
 
     /// Gets changelog
     pub async fn get_changelog(&self) -> Result<serde_json::Value, Error> {
+        self.record("get_changelog", None);
         Ok(serde_json::json!({
             "0.1.0": {
                 "added": ["Initial release"]
@@ -2059,11 +2232,13 @@ impl MockApiClient {
         _user_id: i64,
         _asset_uuid: &str,
     ) -> Result<crate::model::RegisteredUserSummary, Error> {
+        self.record("get_registered_user_summary", None);
         Err(Error::RequestFailed("Not yet implemented".to_string()))
     }
 
     /// Registers asset as authorized
     pub async fn register_asset_authorized(&self, asset_uuid: &str) -> Result<Asset, Error> {
+        self.record("register_asset_authorized", None);
         let mut assets = self.inner.assets.lock().unwrap();
         let asset = assets
             .get_mut(asset_uuid)
@@ -2080,6 +2255,7 @@ impl MockApiClient {
         _user_id: i64,
         _request: &crate::model::GaidRequest,
     ) -> Result<(), Error> {
+        self.record("set_default_gaid_for_registered_user", None);
         Ok(())
     }
 }
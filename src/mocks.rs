@@ -1552,3 +1552,470 @@ pub fn mock_change_manager_password(server: &MockServer) {
             }));
     });
 }
+
+/// Sets up a mock for the `GET /assets/{asset_uuid}/txs` endpoint.
+///
+/// This mock returns the initial issuance transaction of `mock_asset_id`,
+/// issuing 21 million units (2.1e15 base units) in `mock_issuance_txid`.
+pub fn mock_get_asset_issuance_transactions(server: &MockServer) {
+    server.mock(|when, then| {
+        when.method(GET).path("/assets/mock_asset_uuid/txs");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!([
+                {
+                    "txid": "mock_issuance_txid",
+                    "datetime": "2024-01-10T09:00:00Z",
+                    "blockheight": 100,
+                    "is_issuance": true,
+                    "is_reissuance": false,
+                    "is_distribution": false,
+                    "inputs": [],
+                    "outputs": [
+                        {
+                            "asset_id": "mock_asset_id",
+                            "vout": 0,
+                            "amount": 2_100_000_000_000_000_i64,
+                            "asset_blinder": "mock_asset_blinder",
+                            "amount_blinder": "mock_amount_blinder",
+                            "registered_user": null,
+                            "GAID": null,
+                            "is_treasury": true,
+                            "is_spent": false,
+                            "is_burnt": false
+                        }
+                    ],
+                    "unblinded_url": "https://blockstream.info/liquidtestnet/tx/mock_issuance_txid"
+                }
+            ]));
+    });
+}
+
+/// Placeholder pushed onto the script witness of each unsigned input by [`DrySigner`].
+pub const DRY_SIGNER_DUMMY_WITNESS: [u8; 1] = [0x00];
+
+/// A no-op [`Signer`](crate::signer::Signer) for exercising build/broadcast flows in tests.
+///
+/// `DrySigner` never touches key material. By default it decodes the unsigned
+/// transaction and pushes [`DRY_SIGNER_DUMMY_WITNESS`] onto every input that has an
+/// empty script witness, so the result is structurally valid and passes the checks in
+/// [`ElementsRpc::sign_transaction`](crate::ElementsRpc::sign_transaction). A canned
+/// signed hex can be configured instead with [`DrySigner::with_signed_hex`].
+///
+/// Every call is recorded, so tests can assert that the orchestration actually went
+/// through the signer before broadcasting.
+///
+/// # Examples
+/// ```
+/// # use amp_rs::mocks::DrySigner;
+/// let signer = DrySigner::new();
+/// assert_eq!(signer.call_count(), 0);
+///
+/// let canned = DrySigner::with_signed_hex("0200000001...");
+/// assert!(canned.received_transactions().is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DrySigner {
+    signed_hex: Option<String>,
+    calls: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl DrySigner {
+    /// Creates a signer that adds a dummy witness to each unsigned input
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a signer that ignores its input and always returns `signed_hex`
+    #[must_use]
+    pub fn with_signed_hex(signed_hex: impl Into<String>) -> Self {
+        Self {
+            signed_hex: Some(signed_hex.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Number of times `sign_transaction` has been called
+    ///
+    /// # Panics
+    /// Panics if the call log mutex is poisoned
+    #[must_use]
+    pub fn call_count(&self) -> usize {
+        self.calls.lock().unwrap().len()
+    }
+
+    /// Unsigned transactions passed to `sign_transaction`, in call order
+    ///
+    /// # Panics
+    /// Panics if the call log mutex is poisoned
+    #[must_use]
+    pub fn received_transactions(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn add_dummy_witness(unsigned_tx: &str) -> Result<String, crate::signer::SignerError> {
+        use crate::signer::SignerError;
+
+        let tx_bytes = hex::decode(unsigned_tx)?;
+        let mut tx: elements::Transaction = elements::encode::deserialize(&tx_bytes)
+            .map_err(|e| SignerError::InvalidTransaction(format!("Failed to decode: {e}")))?;
+
+        for input in &mut tx.input {
+            if input.witness.script_witness.is_empty() {
+                input
+                    .witness
+                    .script_witness
+                    .push(DRY_SIGNER_DUMMY_WITNESS.to_vec());
+            }
+        }
+
+        Ok(elements::encode::serialize_hex(&tx))
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::signer::Signer for DrySigner {
+    async fn sign_transaction(
+        &self,
+        unsigned_tx: &str,
+    ) -> Result<String, crate::signer::SignerError> {
+        self.calls.lock().unwrap().push(unsigned_tx.to_string());
+
+        match &self.signed_hex {
+            Some(signed_hex) => Ok(signed_hex.clone()),
+            None => Self::add_dummy_witness(unsigned_tx),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// A scripted stand-in for an Elements node's JSON-RPC interface
+///
+/// A plain `httpmock` mock serves the same response for every matching request,
+/// which makes polling loops such as
+/// [`ElementsRpc::wait_for_confirmations`](crate::ElementsRpc::wait_for_confirmations)
+/// awkward to test. `MockElementsRpc` instead answers each request from a small
+/// in-process JSON-RPC handler that keeps per-transaction state: each
+/// `gettransaction` call for a txid configured with
+/// [`set_confirmations_sequence`](Self::set_confirmations_sequence) returns the next
+/// value of its sequence, and the last value is repeated once the sequence is exhausted.
+/// Outputs configured with
+/// [`set_unspent_confirmations_sequence`](Self::set_unspent_confirmations_sequence)
+/// are listed by `listunspent` the same way. Errors queued with
+/// [`queue_errors`](Self::queue_errors) are returned before any of this.
+///
+/// Unknown transactions are answered with Elements' `-5` error and any other method
+/// with `-32601` (method not found).
+///
+/// # Examples
+/// ```
+/// # use amp_rs::mocks::MockElementsRpc;
+/// # use amp_rs::client::ConfirmationConfig;
+/// # #[tokio::main]
+/// # async fn main() {
+/// let node = MockElementsRpc::start();
+/// let txid = "7ceabde8d7c1596b8b4af27286681dbde9c1551614b9788b6f84b9a3789d3184";
+/// node.set_confirmations_sequence(txid, &[0, 1, 2]);
+///
+/// let config = ConfirmationConfig::default()
+///     .with_min_confirmations(2)
+///     .with_poll_interval_secs(0);
+/// let tx = node
+///     .rpc()
+///     .wait_for_confirmations_with_config(txid, &config)
+///     .await
+///     .unwrap();
+/// assert_eq!(tx.confirmations, 2);
+/// assert_eq!(node.call_count("gettransaction"), 3);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockElementsRpc {
+    address: std::net::SocketAddr,
+    state: std::sync::Arc<std::sync::Mutex<MockElementsRpcState>>,
+    shutdown: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[derive(Debug, Default)]
+struct MockElementsRpcState {
+    confirmations: std::collections::HashMap<String, std::collections::VecDeque<u32>>,
+    unspent: std::collections::BTreeMap<(String, u32), std::collections::VecDeque<u32>>,
+    errors: std::collections::HashMap<String, std::collections::VecDeque<i32>>,
+    calls: Vec<String>,
+}
+
+impl MockElementsRpc {
+    /// Starts the mock node on an ephemeral local port
+    ///
+    /// Requests are served by a background thread, one connection at a time,
+    /// until the `MockElementsRpc` is dropped.
+    ///
+    /// # Panics
+    /// Panics if no local port can be bound
+    #[must_use]
+    pub fn start() -> Self {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")
+            .expect("Failed to bind mock Elements RPC listener");
+        let address = listener
+            .local_addr()
+            .expect("Failed to read mock Elements RPC address");
+        let state = std::sync::Arc::new(std::sync::Mutex::new(MockElementsRpcState::default()));
+        let shutdown = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let thread_state = state.clone();
+        let thread_shutdown = shutdown.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if thread_shutdown.load(std::sync::atomic::Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    Self::serve(stream, &thread_state);
+                }
+            }
+        });
+
+        Self {
+            address,
+            state,
+            shutdown,
+        }
+    }
+
+    /// Base URL of the mock node
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Creates an [`ElementsRpc`](crate::ElementsRpc) client pointed at the mock node
+    #[must_use]
+    pub fn rpc(&self) -> crate::ElementsRpc {
+        crate::ElementsRpc::new(self.url(), "user".to_string(), "pass".to_string())
+    }
+
+    /// Scripts the confirmation counts returned by successive `gettransaction` calls for `txid`
+    ///
+    /// Replaces any sequence previously set for the same txid. An empty sequence makes
+    /// the transaction unknown again.
+    ///
+    /// # Panics
+    /// Panics if the mock state mutex is poisoned
+    pub fn set_confirmations_sequence(&self, txid: &str, confirmations: &[u32]) {
+        let mut state = self.state.lock().unwrap();
+        if confirmations.is_empty() {
+            state.confirmations.remove(txid);
+        } else {
+            state
+                .confirmations
+                .insert(txid.to_string(), confirmations.iter().copied().collect());
+        }
+    }
+
+    /// Scripts the confirmation counts `listunspent` reports for the output `txid:vout`
+    ///
+    /// Each `listunspent` call lists every scripted output with the next value
+    /// of its sequence. An empty sequence removes the output.
+    ///
+    /// # Panics
+    /// Panics if the mock state mutex is poisoned
+    pub fn set_unspent_confirmations_sequence(&self, txid: &str, vout: u32, confirmations: &[u32]) {
+        let mut state = self.state.lock().unwrap();
+        let outpoint = (txid.to_string(), vout);
+        if confirmations.is_empty() {
+            state.unspent.remove(&outpoint);
+        } else {
+            state
+                .unspent
+                .insert(outpoint, confirmations.iter().copied().collect());
+        }
+    }
+
+    /// Answers the next `count` calls of `method` with the JSON-RPC error `code`
+    ///
+    /// Errors are sent with a `500` status, as Elements does, and queue behind
+    /// any errors already queued for `method`.
+    ///
+    /// # Panics
+    /// Panics if the mock state mutex is poisoned
+    pub fn queue_errors(&self, method: &str, code: i32, count: usize) {
+        self.state
+            .lock()
+            .unwrap()
+            .errors
+            .entry(method.to_string())
+            .or_default()
+            .extend(std::iter::repeat_n(code, count));
+    }
+
+    /// Number of requests for the RPC `method` the node has answered
+    ///
+    /// # Panics
+    /// Panics if the mock state mutex is poisoned
+    #[must_use]
+    pub fn call_count(&self, method: &str) -> usize {
+        self.state
+            .lock()
+            .unwrap()
+            .calls
+            .iter()
+            .filter(|call| *call == method)
+            .count()
+    }
+
+    /// Reads one HTTP request from `stream` and writes the handler's response
+    fn serve(mut stream: std::net::TcpStream, state: &std::sync::Mutex<MockElementsRpcState>) {
+        use std::io::Write;
+
+        let _ = stream.set_read_timeout(Some(std::time::Duration::from_secs(5)));
+        let Some(body) = Self::read_request_body(&mut stream) else {
+            return;
+        };
+
+        let (status, response) = match serde_json::from_slice::<serde_json::Value>(&body) {
+            Ok(request) => state.lock().unwrap().handle(&request),
+            Err(e) => MockElementsRpcState::error(400, -32700, &format!("Parse error: {e}")),
+        };
+
+        let payload = response.to_string();
+        let _ = write!(
+            stream,
+            "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{payload}",
+            payload.len()
+        );
+        let _ = stream.flush();
+    }
+
+    /// Reads the request head and returns the `Content-Length` bytes of body
+    /// that follow it
+    fn read_request_body(stream: &mut std::net::TcpStream) -> Option<Vec<u8>> {
+        use std::io::Read;
+
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 4096];
+        let header_end = loop {
+            let read = stream.read(&mut chunk).ok()?;
+            if read == 0 {
+                return None;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+            if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+                break pos + 4;
+            }
+        };
+
+        let head = String::from_utf8_lossy(&buffer[..header_end]).to_lowercase();
+        let content_length = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+
+        while buffer.len() < header_end + content_length {
+            let read = stream.read(&mut chunk).ok()?;
+            if read == 0 {
+                return None;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+
+        Some(buffer[header_end..header_end + content_length].to_vec())
+    }
+}
+
+impl MockElementsRpcState {
+    /// Error response for the JSON-RPC `code`, with the status Elements uses
+    fn error(status: u16, code: i32, message: &str) -> (u16, serde_json::Value) {
+        (
+            status,
+            json!({"result": null, "error": {"code": code, "message": message}, "id": "amp-client"}),
+        )
+    }
+
+    /// Next value of a scripted sequence, holding on its last value
+    fn advance(sequence: &mut std::collections::VecDeque<u32>) -> Option<u32> {
+        if sequence.len() > 1 {
+            sequence.pop_front()
+        } else {
+            sequence.front().copied()
+        }
+    }
+
+    /// Records the call and answers one JSON-RPC request from the scripts
+    fn handle(&mut self, request: &serde_json::Value) -> (u16, serde_json::Value) {
+        let method = request
+            .get("method")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default();
+        self.calls.push(method.to_string());
+
+        if let Some(code) = self
+            .errors
+            .get_mut(method)
+            .and_then(std::collections::VecDeque::pop_front)
+        {
+            return Self::error(500, code, "Queued mock error");
+        }
+
+        match method {
+            "listunspent" => {
+                let unspent: Vec<serde_json::Value> = self
+                    .unspent
+                    .iter_mut()
+                    .map(|((txid, vout), sequence)| {
+                        json!({
+                            "txid": txid,
+                            "vout": vout,
+                            "amount": 1.0,
+                            "asset": "0".repeat(64),
+                            "address": "mock_address",
+                            "spendable": true,
+                            "solvable": true,
+                            "confirmations": Self::advance(sequence).unwrap_or_default()
+                        })
+                    })
+                    .collect();
+                (
+                    200,
+                    json!({"result": unspent, "error": null, "id": "amp-client"}),
+                )
+            }
+            "gettransaction" => {
+                let txid = request
+                    .pointer("/params/0")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default();
+                match self.confirmations.get_mut(txid).and_then(Self::advance) {
+                    Some(confirmations) => (
+                        200,
+                        json!({
+                            "result": {
+                                "txid": txid,
+                                "confirmations": confirmations,
+                                "hex": "",
+                                "details": []
+                            },
+                            "error": null,
+                            "id": "amp-client"
+                        }),
+                    ),
+                    None => Self::error(500, -5, "Invalid or non-wallet transaction id"),
+                }
+            }
+            _ => Self::error(404, -32601, "Method not found"),
+        }
+    }
+}
+
+impl Drop for MockElementsRpc {
+    fn drop(&mut self) {
+        self.shutdown
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        // Wake the accept loop so the background thread can exit
+        let _ = std::net::TcpStream::connect(self.address);
+    }
+}
@@ -79,6 +79,14 @@ pub struct Asset {
     pub transfer_restricted: bool,
 }
 
+impl Asset {
+    /// Whether AMP only lets whitelisted registered users receive this asset
+    #[must_use]
+    pub const fn is_transfer_restricted(&self) -> bool {
+        self.transfer_restricted
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct IssuanceRequest {
     pub name: String,
@@ -155,7 +163,7 @@ pub struct RegisteredUserResponse {
     pub creator: i64,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RegisteredUserAdd {
     pub name: String,
     #[serde(rename = "GAID")]
@@ -203,7 +211,30 @@ pub struct CategoryEdit {
 #[derive(Debug, Deserialize)]
 pub struct ValidateGaidResponse {
     pub is_valid: bool,
+    /// Why the GAID is invalid; some responses name this field `reason`
+    #[serde(default, alias = "reason")]
     pub error: Option<String>,
+    /// Machine-readable code for the failure, when AMP provides one
+    #[serde(default)]
+    pub error_code: Option<String>,
+}
+
+impl ValidateGaidResponse {
+    /// Returns whether AMP accepted the GAID
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    /// Returns why the GAID was rejected, or `None` for a valid GAID
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        if self.is_valid {
+            None
+        } else {
+            self.error.as_deref()
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -270,6 +301,16 @@ const fn default_ready_for_distribution() -> bool {
     false
 }
 
+impl CreateAssetAssignmentRequest {
+    /// Serializes the request as canonical JSON for hashing and idempotency keys
+    ///
+    /// See [`canonical_json`] for the format.
+    #[must_use]
+    pub fn canonical_json(&self) -> String {
+        canonical_json(self)
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct CreateAssetAssignmentRequestWrapper {
     pub assignments: Vec<CreateAssetAssignmentRequest>,
@@ -322,6 +363,63 @@ pub struct Activity {
     pub amount: i64,
 }
 
+/// Category of an [`Activity`] entry, derived from its `type` field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActivityKind {
+    /// Initial issuance of the asset
+    Issuance,
+    /// Reissuance adding to the asset's supply
+    Reissuance,
+    /// Distribution of the asset to registered users
+    Distribution,
+    /// Burn removing part of the asset's supply
+    Burn,
+    /// Assignment of the asset to a registered user
+    Assignment,
+    /// Any activity type not covered above
+    Other,
+}
+
+impl Activity {
+    /// Classifies the entry by its `type` field
+    ///
+    /// Matching is case-insensitive and tolerates prefixes or suffixes such as
+    /// `asset_reissuance`, so feeds can be grouped without string-matching.
+    #[must_use]
+    pub fn kind(&self) -> ActivityKind {
+        let activity_type = self.activity_type.to_ascii_lowercase();
+        if activity_type.contains("reissu") {
+            ActivityKind::Reissuance
+        } else if activity_type.contains("issu") {
+            ActivityKind::Issuance
+        } else if activity_type.contains("distribut") {
+            ActivityKind::Distribution
+        } else if activity_type.contains("burn") {
+            ActivityKind::Burn
+        } else if activity_type.contains("assign") {
+            ActivityKind::Assignment
+        } else {
+            ActivityKind::Other
+        }
+    }
+
+    /// Parses `datetime` as a UTC timestamp
+    ///
+    /// Accepts RFC 3339 as well as timestamps without an offset, which are
+    /// taken to be UTC. Returns `None` when `datetime` matches neither form.
+    #[must_use]
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        if let Ok(timestamp) = DateTime::parse_from_rfc3339(&self.datetime) {
+            return Some(timestamp.with_timezone(&Utc));
+        }
+
+        ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+            .iter()
+            .find_map(|format| chrono::NaiveDateTime::parse_from_str(&self.datetime, format).ok())
+            .map(|naive| naive.and_utc())
+    }
+}
+
 #[derive(Debug, Serialize, Default)]
 pub struct AssetActivityParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -537,6 +635,168 @@ pub struct AssetSummary {
     pub reissuance_tokens: i64,
 }
 
+impl AssetSummary {
+    /// Compares this summary with a later snapshot of the same asset
+    ///
+    /// `self` is treated as the earlier snapshot: each change reports this
+    /// summary's value as `before` and `other`'s as `after`. Counters are
+    /// listed in declaration order.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> AssetSummaryDiff {
+        let counters = [
+            (AssetSummaryField::Issued, self.issued, other.issued),
+            (AssetSummaryField::Reissued, self.reissued, other.reissued),
+            (AssetSummaryField::Assigned, self.assigned, other.assigned),
+            (
+                AssetSummaryField::Distributed,
+                self.distributed,
+                other.distributed,
+            ),
+            (AssetSummaryField::Burned, self.burned, other.burned),
+            (
+                AssetSummaryField::Blacklisted,
+                self.blacklisted,
+                other.blacklisted,
+            ),
+            (
+                AssetSummaryField::RegisteredUsers,
+                self.registered_users,
+                other.registered_users,
+            ),
+            (
+                AssetSummaryField::ActiveRegisteredUsers,
+                self.active_registered_users,
+                other.active_registered_users,
+            ),
+            (
+                AssetSummaryField::ActiveGreenSubaccounts,
+                self.active_green_subaccounts,
+                other.active_green_subaccounts,
+            ),
+            (
+                AssetSummaryField::ReissuanceTokens,
+                self.reissuance_tokens,
+                other.reissuance_tokens,
+            ),
+        ];
+
+        AssetSummaryDiff {
+            changes: counters
+                .into_iter()
+                .filter(|(_, before, after)| before != after)
+                .map(|(field, before, after)| AssetSummaryChange {
+                    field,
+                    before,
+                    after,
+                })
+                .collect(),
+            reissuance_token_id: (self.reissuance_token_id != other.reissuance_token_id).then(
+                || {
+                    (
+                        self.reissuance_token_id.clone(),
+                        other.reissuance_token_id.clone(),
+                    )
+                },
+            ),
+        }
+    }
+}
+
+/// A counter field of [`AssetSummary`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetSummaryField {
+    Issued,
+    Reissued,
+    Assigned,
+    Distributed,
+    Burned,
+    Blacklisted,
+    RegisteredUsers,
+    ActiveRegisteredUsers,
+    ActiveGreenSubaccounts,
+    ReissuanceTokens,
+}
+
+impl AssetSummaryField {
+    /// The field's name in [`AssetSummary`] and the AMP API, e.g. `"issued"`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Issued => "issued",
+            Self::Reissued => "reissued",
+            Self::Assigned => "assigned",
+            Self::Distributed => "distributed",
+            Self::Burned => "burned",
+            Self::Blacklisted => "blacklisted",
+            Self::RegisteredUsers => "registered_users",
+            Self::ActiveRegisteredUsers => "active_registered_users",
+            Self::ActiveGreenSubaccounts => "active_green_subaccounts",
+            Self::ReissuanceTokens => "reissuance_tokens",
+        }
+    }
+}
+
+impl std::fmt::Display for AssetSummaryField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A counter of [`AssetSummary`] that differs between two snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetSummaryChange {
+    /// Which counter changed
+    pub field: AssetSummaryField,
+    /// Value in the earlier snapshot (`self` in [`AssetSummary::diff`])
+    pub before: i64,
+    /// Value in the later snapshot (`other` in [`AssetSummary::diff`])
+    pub after: i64,
+}
+
+impl AssetSummaryChange {
+    /// `after - before`
+    #[must_use]
+    pub const fn delta(&self) -> i64 {
+        self.after - self.before
+    }
+}
+
+/// What changed between two [`AssetSummary`] snapshots, from [`AssetSummary::diff`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetSummaryDiff {
+    /// Counters that changed, in [`AssetSummary`] field order
+    pub changes: Vec<AssetSummaryChange>,
+    /// `(before, after)` if the reissuance token id changed
+    pub reissuance_token_id: Option<(Option<String>, Option<String>)>,
+}
+
+impl AssetSummaryDiff {
+    /// Whether the snapshots are identical
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.reissuance_token_id.is_none()
+    }
+
+    /// The change to `field`, if it changed
+    #[must_use]
+    pub fn get(&self, field: AssetSummaryField) -> Option<&AssetSummaryChange> {
+        self.changes.iter().find(|change| change.field == field)
+    }
+
+    /// Change in supply, counting issuances and reissuances less burns
+    #[must_use]
+    pub fn supply_delta(&self) -> i64 {
+        [AssetSummaryField::Issued, AssetSummaryField::Reissued]
+            .into_iter()
+            .filter_map(|field| self.get(field))
+            .map(AssetSummaryChange::delta)
+            .sum::<i64>()
+            - self
+                .get(AssetSummaryField::Burned)
+                .map_or(0, AssetSummaryChange::delta)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Utxo {
     pub txid: String,
@@ -548,16 +808,64 @@ pub struct Utxo {
     pub blacklisted: bool,
 }
 
+/// Stage of a [`Reissuance`] in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReissuanceState {
+    /// Requested from AMP; no transaction has been broadcast yet
+    Requested,
+    /// Transaction broadcast but not yet included in a block
+    Broadcast,
+    /// Transaction confirmed on-chain
+    Confirmed,
+}
+
+/// Deserializes `null` as the type's default value
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+/// A reissuance of an asset
+///
+/// Fields AMP leaves empty or `null` before the reissuance is broadcast or
+/// confirmed deserialize to their defaults; use [`Reissuance::state`] to tell
+/// the stages apart.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Reissuance {
+    #[serde(default, deserialize_with = "null_as_default")]
     pub txid: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub vout: i64,
     pub destination_address: String,
     pub reissuance_amount: i64,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub confirmed_in_block: String,
     pub created: String,
 }
 
+impl Reissuance {
+    /// Returns how far the reissuance has progressed
+    #[must_use]
+    pub fn state(&self) -> ReissuanceState {
+        if self.txid.is_empty() {
+            ReissuanceState::Requested
+        } else if self.confirmed_in_block.is_empty() {
+            ReissuanceState::Broadcast
+        } else {
+            ReissuanceState::Confirmed
+        }
+    }
+
+    /// Returns the reissuance transaction id, or `None` before it is broadcast
+    #[must_use]
+    pub fn txid(&self) -> Option<String> {
+        (!self.txid.is_empty()).then(|| self.txid.clone())
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReissueRequest {
     pub amount_to_reissue: i64,
@@ -696,6 +1004,22 @@ impl TokenData {
         Utc::now() > self.expires_at
     }
 
+    /// Checks if the token is expired, allowing `tolerance` past its expiry for clock skew
+    ///
+    /// # Examples
+    /// ```
+    /// # use amp_rs::model::TokenData;
+    /// # use chrono::{Utc, Duration};
+    /// let expires_at = Utc::now() - Duration::seconds(10);
+    /// let token_data = TokenData::new("token".to_string(), expires_at);
+    /// assert!(token_data.is_expired());
+    /// assert!(!token_data.is_expired_with_tolerance(Duration::seconds(30)));
+    /// ```
+    #[must_use]
+    pub fn is_expired_with_tolerance(&self, tolerance: Duration) -> bool {
+        Utc::now() > self.expires_at + tolerance
+    }
+
     /// Checks if the token expires within the given threshold
     ///
     /// # Examples
@@ -772,6 +1096,56 @@ pub struct AssetDistributionAssignment {
     pub amount: f64,
 }
 
+/// Decimal places Elements RPC uses for every asset amount
+///
+/// The node knows nothing of an asset's registered precision: one base unit
+/// is always `0.00000001`, so AMP base-unit amounts are converted for the
+/// node with this precision.
+pub const NODE_AMOUNT_PRECISION: u8 = 8;
+
+/// Converts an integer AMP amount in base units to a float with `precision` decimals
+///
+/// With [`NODE_AMOUNT_PRECISION`] this is the amount Elements RPC expects;
+/// with the asset's registered precision it is the whole-asset amount.
+///
+/// # Examples
+/// ```
+/// # use amp_rs::model::{amp_amount_to_node, NODE_AMOUNT_PRECISION};
+/// assert_eq!(amp_amount_to_node(150_000_000, NODE_AMOUNT_PRECISION), 1.5);
+/// assert_eq!(amp_amount_to_node(150, 2), 1.5);
+/// assert_eq!(amp_amount_to_node(150, 0), 150.0);
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn amp_amount_to_node(amount: u64, precision: u8) -> f64 {
+    amount as f64 / 10_f64.powi(i32::from(precision))
+}
+
+/// Converts a float amount with `precision` decimals to an integer AMP amount in base units
+///
+/// The inverse of [`amp_amount_to_node`]. The result is rounded to the
+/// nearest base unit, so float noise such as `0.30000000000000004` does not
+/// lose a unit; negative and non-finite amounts give `0`, and amounts
+/// beyond `u64::MAX` saturate.
+///
+/// # Examples
+/// ```
+/// # use amp_rs::model::{node_amount_to_amp, NODE_AMOUNT_PRECISION};
+/// assert_eq!(node_amount_to_amp(0.1 + 0.2, NODE_AMOUNT_PRECISION), 30_000_000);
+/// assert_eq!(node_amount_to_amp(1.5, 2), 150);
+/// assert_eq!(node_amount_to_amp(-1.0, 8), 0);
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn node_amount_to_amp(amount: f64, precision: u8) -> u64 {
+    let scaled = (amount * 10_f64.powi(i32::from(precision))).round();
+    if scaled.is_finite() {
+        scaled.max(0.0) as u64
+    } else {
+        0
+    }
+}
+
 /// Assignment for distribution creation API request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DistributionAssignmentRequest {
@@ -780,10 +1154,16 @@ pub struct DistributionAssignmentRequest {
     pub address: String,
 }
 
+/// Longest distribution reference AMP accepts, in characters
+pub const MAX_DISTRIBUTION_REFERENCE_LEN: usize = 255;
+
 /// Request payload for distribution creation API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateDistributionRequest {
     pub assignments: Vec<DistributionAssignmentRequest>,
+    /// Operator reference stored with the distribution for reconciliation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
 }
 
 /// UTXO information from Elements node
@@ -873,13 +1253,87 @@ pub struct TxInput {
     pub sequence: Option<u32>,
 }
 
+/// One entry of the `outputs` array passed to `createrawtransaction`
+///
+/// Serializes to the object shape Elements expects, keyed by the destination
+/// with the amount as an 8-decimal string: `{"<address>": "1.50000000", "asset": "<id>"}`.
+/// The special destinations `burn` and `fee` are accepted in place of an address.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementsOutput {
+    pub address: String,
+    pub amount: f64,
+    pub asset: String,
+}
+
+impl ElementsOutput {
+    /// Creates an output paying `amount` of `asset` to `address`
+    #[must_use]
+    pub fn new(address: impl Into<String>, amount: f64, asset: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            amount,
+            asset: asset.into(),
+        }
+    }
+}
+
+impl Serialize for ElementsOutput {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry(&self.address, &format!("{:.8}", self.amount))?;
+        map.serialize_entry("asset", &self.asset)?;
+        map.end()
+    }
+}
+
+/// Lifecycle status of a distribution on the AMP side
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DistributionStatus {
+    /// Created but no transaction has been confirmed with AMP yet
+    Pending,
+    /// Transaction submitted to AMP but not yet confirmed on-chain
+    Unconfirmed,
+    /// Transaction confirmed and the distribution is complete
+    Confirmed,
+    /// AMP rejected or abandoned the distribution
+    Failed,
+    /// A status this client does not recognize
+    #[serde(other)]
+    Unknown,
+}
+
 /// Response from distribution creation API
+///
+/// The same shape is used when reading a distribution back with
+/// [`ApiClient::get_distribution`](crate::ApiClient::get_distribution); fields the
+/// endpoint does not return are left at their defaults.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DistributionResponse {
     pub distribution_uuid: String,
+    #[serde(default)]
     pub map_address_amount: std::collections::HashMap<String, f64>,
+    #[serde(default)]
     pub map_address_asset: std::collections::HashMap<String, String>,
+    #[serde(default)]
     pub asset_id: String,
+    /// Status reported by AMP; absent from `distributions/create` responses
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distribution_status: Option<DistributionStatus>,
+}
+
+impl DistributionResponse {
+    /// Returns the AMP-side status of the distribution
+    ///
+    /// Freshly created distributions carry no status and are reported as
+    /// [`DistributionStatus::Pending`].
+    #[must_use]
+    pub fn status(&self) -> DistributionStatus {
+        self.distribution_status
+            .unwrap_or(DistributionStatus::Pending)
+    }
 }
 
 /// Address information from listreceivedbyaddress RPC
@@ -897,11 +1351,61 @@ pub struct ReceivedByAddress {
     pub txids: Option<Vec<String>>,
 }
 
+impl ReceivedByAddress {
+    /// Returns the amount of `asset_id` received by this address
+    ///
+    /// Elements reports `amount` as a map keyed by asset id (or asset label, such as
+    /// `bitcoin` for L-BTC). Assets the address never received return `0.0`.
+    #[must_use]
+    pub fn amount_for(&self, asset_id: &str) -> f64 {
+        self.amount
+            .as_ref()
+            .and_then(|amounts| amounts.get(asset_id))
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
+
 /// Transaction data for distribution confirmation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DistributionTxData {
     pub details: TransactionDetail,
     pub txid: String,
+    /// Outpoints spent by the transaction, set with [`Self::with_inputs`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<DistributionTxInput>,
+}
+
+/// An input spent by a distribution transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DistributionTxInput {
+    pub txid: String,
+    pub vout: u32,
+    pub asset: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amountblinder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assetblinder: Option<String>,
+}
+
+impl DistributionTxData {
+    /// Sets the spent inputs from the UTXOs a distribution transaction was built from
+    ///
+    /// `utxos` is expected to be the selected UTXO list returned by
+    /// [`ElementsRpc::build_distribution_transaction`](crate::ElementsRpc::build_distribution_transaction).
+    /// Blinders are copied for confidential UTXOs; any previously set inputs are replaced.
+    pub fn with_inputs(&mut self, utxos: &[Unspent]) {
+        self.inputs = utxos
+            .iter()
+            .map(|utxo| DistributionTxInput {
+                txid: utxo.txid.clone(),
+                vout: utxo.vout,
+                asset: utxo.asset.clone(),
+                amountblinder: utxo.amountblinder.clone(),
+                assetblinder: utxo.assetblinder.clone(),
+            })
+            .collect();
+    }
 }
 
 /// Transaction data for AMP API confirmation (matches Python implementation)
@@ -919,6 +1423,436 @@ pub struct ConfirmDistributionRequest {
     pub change_data: Vec<Unspent>,
 }
 
+impl ConfirmDistributionRequest {
+    /// Builds a confirmation request from the change outputs of a distribution transaction
+    ///
+    /// `change_utxos` is expected to be the output of
+    /// [`ElementsRpc::collect_change_data`](crate::ElementsRpc::collect_change_data).
+    /// Every UTXO must belong to `txid` and carry both its asset and amount blinders,
+    /// since AMP needs them to unblind the change. The `tx_data.details` field starts
+    /// out empty; attach the `gettransaction` details with [`Self::with_details`].
+    ///
+    /// # Errors
+    /// Returns `AmpError::Validation` if a UTXO belongs to a different transaction
+    /// or is missing a blinder
+    ///
+    /// # Examples
+    /// ```
+    /// # use amp_rs::model::{ConfirmDistributionRequest, Unspent};
+    /// let change = Unspent {
+    ///     txid: "distribution_txid".to_string(),
+    ///     vout: 2,
+    ///     amount: 5.0,
+    ///     asset: "asset_id".to_string(),
+    ///     address: "change_address".to_string(),
+    ///     spendable: true,
+    ///     confirmations: Some(2),
+    ///     scriptpubkey: None,
+    ///     redeemscript: None,
+    ///     witnessscript: None,
+    ///     amountblinder: Some("aa".repeat(32)),
+    ///     assetblinder: Some("bb".repeat(32)),
+    /// };
+    ///
+    /// let request =
+    ///     ConfirmDistributionRequest::from_change("dist-uuid", "distribution_txid", &[change])
+    ///         .unwrap();
+    /// assert_eq!(request.tx_data.txid, "distribution_txid");
+    /// assert_eq!(request.change_data.len(), 1);
+    /// ```
+    pub fn from_change(
+        distribution_uuid: &str,
+        txid: &str,
+        change_utxos: &[Unspent],
+    ) -> Result<Self, crate::client::AmpError> {
+        for utxo in change_utxos {
+            if utxo.txid != txid {
+                return Err(crate::client::AmpError::validation(format!(
+                    "Change output {}:{} does not belong to distribution {distribution_uuid} transaction {txid}",
+                    utxo.txid, utxo.vout
+                )));
+            }
+
+            let has_blinder =
+                |blinder: &Option<String>| blinder.as_deref().is_some_and(|b| !b.is_empty());
+            if !has_blinder(&utxo.assetblinder) || !has_blinder(&utxo.amountblinder) {
+                return Err(crate::client::AmpError::validation(format!(
+                    "Change output {txid}:{} for distribution {distribution_uuid} is missing blinding data. \
+                    Use list_unspent_with_blinding_data to collect change outputs.",
+                    utxo.vout
+                )));
+            }
+        }
+
+        Ok(Self {
+            tx_data: AmpTxData {
+                details: serde_json::Value::Array(Vec::new()),
+                txid: txid.to_string(),
+            },
+            change_data: change_utxos.to_vec(),
+        })
+    }
+
+    /// Sets the `gettransaction` details sent as `tx_data.details`
+    #[must_use]
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.tx_data.details = details;
+        self
+    }
+
+    /// Serializes the request as canonical JSON for hashing and idempotency keys
+    ///
+    /// See [`canonical_json`] for the format.
+    #[must_use]
+    pub fn canonical_json(&self) -> String {
+        canonical_json(self)
+    }
+}
+
+/// Serializes `value` as compact JSON with object keys sorted at every level
+///
+/// Struct fields otherwise appear in declaration order and free-form maps in
+/// whatever order `serde_json` keeps them (insertion order when a dependency
+/// enables its `preserve_order` feature), so equal values could hash
+/// differently. Values that cannot be represented as JSON yield `"null"`.
+#[must_use]
+pub fn canonical_json<T: Serialize + ?Sized>(value: &T) -> String {
+    fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut entries: Vec<(String, serde_json::Value)> = map.into_iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                serde_json::Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, sort_keys(value)))
+                        .collect(),
+                )
+            }
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.into_iter().map(sort_keys).collect())
+            }
+            other => other,
+        }
+    }
+
+    serde_json::to_value(value)
+        .map(sort_keys)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Checks that `value` is a 64-character hex identifier
+///
+/// `kind` names the identifier in the error message, e.g. `asset id`.
+/// Uppercase digits are rejected unless `allow_uppercase` is set.
+fn validate_hex_id(
+    kind: &str,
+    value: &str,
+    allow_uppercase: bool,
+) -> Result<(), crate::client::AmpError> {
+    if value.len() != 64 {
+        return Err(crate::client::AmpError::validation(format!(
+            "Invalid {kind} '{value}': expected 64 hex characters, got {}",
+            value.len()
+        )));
+    }
+    if let Some((position, c)) = value.char_indices().find(|(_, c)| {
+        !(matches!(c, '0'..='9' | 'a'..='f') || allow_uppercase && matches!(c, 'A'..='F'))
+    }) {
+        let reason = if c.is_ascii_hexdigit() {
+            "hex digits must be lowercase"
+        } else {
+            "not a hex digit"
+        };
+        return Err(crate::client::AmpError::validation(format!(
+            "Invalid {kind} '{value}': character '{c}' at position {position} is {reason}"
+        )));
+    }
+    Ok(())
+}
+
+/// A validated Liquid asset id: 64 lowercase hex characters
+///
+/// Parsing rejects malformed ids before they reach the node, where a typo
+/// would otherwise surface as an unrelated RPC failure.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AssetId(String);
+
+impl AssetId {
+    /// Returns the asset id as a hex string
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for AssetId {
+    type Err = crate::client::AmpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate_hex_id("asset id", s, false)?;
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for AssetId {
+    type Error = crate::client::AmpError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        validate_hex_id("asset id", &value, false)?;
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<&str> for AssetId {
+    type Error = crate::client::AmpError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<AssetId> for String {
+    fn from(asset_id: AssetId) -> Self {
+        asset_id.0
+    }
+}
+
+impl AsRef<str> for AssetId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for AssetId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A validated transaction id: 64 hex characters
+///
+/// Uppercase input, as copied from some block explorers, is accepted and
+/// normalized to lowercase.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Txid(String);
+
+impl Txid {
+    /// Returns the txid as a lowercase hex string
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for Txid {
+    type Err = crate::client::AmpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate_hex_id("txid", s, true)?;
+        Ok(Self(s.to_ascii_lowercase()))
+    }
+}
+
+impl TryFrom<String> for Txid {
+    type Error = crate::client::AmpError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Txid {
+    type Error = crate::client::AmpError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Txid> for String {
+    fn from(txid: Txid) -> Self {
+        txid.0
+    }
+}
+
+impl AsRef<str> for Txid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Txid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An Elements network, selecting the address encoding to parse against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElementsNetwork {
+    /// Liquid mainnet (`liquidv1`)
+    Liquid,
+    /// Liquid testnet (`liquidtestnet`)
+    LiquidTestnet,
+    /// Elements regtest and other custom chains using the default prefixes
+    ElementsRegtest,
+}
+
+impl ElementsNetwork {
+    /// Every network, mainnet first
+    pub const ALL: [Self; 3] = [Self::Liquid, Self::LiquidTestnet, Self::ElementsRegtest];
+
+    /// Maps a `getblockchaininfo` chain name to its network
+    ///
+    /// Unknown chain names are treated as regtest, whose address prefixes
+    /// custom Elements chains share by default.
+    #[must_use]
+    pub fn from_chain(chain: &str) -> Self {
+        match chain {
+            "liquidv1" => Self::Liquid,
+            "liquidtestnet" => Self::LiquidTestnet,
+            _ => Self::ElementsRegtest,
+        }
+    }
+
+    /// L-BTC asset id, or `None` on regtest where the policy asset is chain-specific
+    #[must_use]
+    pub const fn lbtc_asset_id(self) -> Option<&'static str> {
+        match self {
+            Self::Liquid => {
+                Some("6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d")
+            }
+            Self::LiquidTestnet => {
+                Some("144c654344aa716d6f3abcc1ca90e5641e4e2a7f633bc09fe3baf64585819a49")
+            }
+            Self::ElementsRegtest => None,
+        }
+    }
+
+    /// Address prefixes and HRPs used on this network
+    #[must_use]
+    pub const fn address_params(self) -> &'static elements::AddressParams {
+        match self {
+            Self::Liquid => &elements::AddressParams::LIQUID,
+            Self::LiquidTestnet => &elements::AddressParams::LIQUID_TESTNET,
+            Self::ElementsRegtest => &elements::AddressParams::ELEMENTS,
+        }
+    }
+}
+
+/// The script template an address pays to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressType {
+    P2pkh,
+    P2sh,
+    P2wpkh,
+    P2wsh,
+    P2tr,
+}
+
+/// Result of [`classify_address`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AddressClass {
+    /// Whether the address carries a blinding key
+    pub confidential: bool,
+    pub address_type: AddressType,
+}
+
+/// Parses `addr` for `network` and reports whether it is confidential and what it pays to
+///
+/// Unlike prefix checks such as `starts_with('v')`, this decodes the address
+/// with the network's parameters, so it holds across networks and for
+/// blech32 (`lq1`, `tlq1`, `el1`) addresses.
+///
+/// # Errors
+/// Returns `AmpError::Validation` if `addr` is not a valid address for
+/// `network` or uses an unsupported witness program
+///
+/// # Examples
+/// ```
+/// # use amp_rs::model::{classify_address, AddressType, ElementsNetwork};
+/// let address = "tex1q50rtrmj2f8vl9tem8qpfw36ylw5jg9j24kmnvl";
+/// let class = classify_address(address, ElementsNetwork::LiquidTestnet).unwrap();
+/// assert!(!class.confidential);
+/// assert_eq!(class.address_type, AddressType::P2wpkh);
+///
+/// // A testnet address is not valid on mainnet
+/// assert!(classify_address(address, ElementsNetwork::Liquid).is_err());
+/// ```
+pub fn classify_address(
+    addr: &str,
+    network: ElementsNetwork,
+) -> Result<AddressClass, crate::client::AmpError> {
+    let address =
+        elements::Address::parse_with_params(addr, network.address_params()).map_err(|e| {
+            crate::client::AmpError::validation(format!(
+                "Invalid {network:?} address '{addr}': {e}"
+            ))
+        })?;
+
+    let address_type = match &address.payload {
+        elements::address::Payload::PubkeyHash(_) => AddressType::P2pkh,
+        elements::address::Payload::ScriptHash(_) => AddressType::P2sh,
+        elements::address::Payload::WitnessProgram { version, program } => {
+            match (version.to_u8(), program.len()) {
+                (0, 20) => AddressType::P2wpkh,
+                (0, 32) => AddressType::P2wsh,
+                (1, 32) => AddressType::P2tr,
+                (version, len) => {
+                    return Err(crate::client::AmpError::validation(format!(
+                        "Unsupported witness program in '{addr}': version {version}, {len} bytes"
+                    )))
+                }
+            }
+        }
+    };
+
+    Ok(AddressClass {
+        confidential: address.is_blinded(),
+        address_type,
+    })
+}
+
+/// Verifies the HMAC-SHA256 signature of an AMP webhook payload
+///
+/// `signature_header` is the hex-encoded HMAC of the raw request body, keyed
+/// with the webhook secret. A `sha256=` prefix is accepted. The digests are
+/// compared in constant time, so timing does not reveal how many leading
+/// bytes of a forged signature were correct.
+///
+/// Returns `false` for malformed headers rather than an error, since the
+/// caller should treat those the same as a mismatch.
+#[must_use]
+pub fn verify_webhook_signature(secret: &[u8], payload: &[u8], signature_header: &str) -> bool {
+    use elements::hashes::{hmac, sha256, Hash, HashEngine, HmacEngine};
+
+    let signature_hex = signature_header.trim();
+    let signature_hex = signature_hex
+        .strip_prefix("sha256=")
+        .unwrap_or(signature_hex);
+    let Ok(signature) = hex::decode(signature_hex) else {
+        return false;
+    };
+
+    let mut engine = HmacEngine::<sha256::Hash>::new(secret);
+    engine.input(payload);
+    let expected = hmac::Hmac::<sha256::Hash>::from_engine(engine);
+    let expected = expected.as_byte_array();
+
+    signature.len() == expected.len()
+        && signature
+            .iter()
+            .zip(expected.iter())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1068,6 +2002,7 @@ mod tests {
             map_address_amount,
             map_address_asset,
             asset_id: "main_asset_id".to_string(),
+            distribution_status: None,
         };
 
         assert_eq!(distribution_response.distribution_uuid, "dist_uuid_123");
@@ -1128,6 +2063,7 @@ mod tests {
 
         let create_request = CreateDistributionRequest {
             assignments: assignments.clone(),
+            reference: None,
         };
 
         assert_eq!(create_request.assignments.len(), 2);
@@ -1159,6 +2095,7 @@ mod tests {
 
         let request = CreateDistributionRequest {
             assignments: vec![assignment],
+            reference: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -1176,6 +2113,26 @@ mod tests {
         assert_eq!(first_assignment["address"], "test_address");
     }
 
+    #[test]
+    fn test_distribution_request_reference_serialization() {
+        let assignment = DistributionAssignmentRequest {
+            user_uuid: "test_user".to_string(),
+            amount: 10.0,
+            address: "test_address".to_string(),
+        };
+        let mut request = CreateDistributionRequest {
+            assignments: vec![assignment],
+            reference: None,
+        };
+
+        let parsed = serde_json::to_value(&request).unwrap();
+        assert!(parsed.get("reference").is_none());
+
+        request.reference = Some("recon-2024-07".to_string());
+        let parsed = serde_json::to_value(&request).unwrap();
+        assert_eq!(parsed["reference"], "recon-2024-07");
+    }
+
     #[test]
     fn test_distribution_tx_data_creation() {
         let tx_detail = TransactionDetail {
@@ -1193,6 +2150,7 @@ mod tests {
         let tx_data = DistributionTxData {
             details: tx_detail.clone(),
             txid: "test_txid_123".to_string(),
+            inputs: Vec::new(),
         };
 
         assert_eq!(tx_data.txid, "test_txid_123");
@@ -1314,6 +2272,109 @@ mod tests {
         let change_data_array = parsed["change_data"].as_array().unwrap();
         assert_eq!(change_data_array.len(), 0);
     }
+
+    #[test]
+    fn test_distribution_response_status_deserialization() {
+        let cases = [
+            ("PENDING", DistributionStatus::Pending),
+            ("UNCONFIRMED", DistributionStatus::Unconfirmed),
+            ("CONFIRMED", DistributionStatus::Confirmed),
+            ("FAILED", DistributionStatus::Failed),
+            ("SOMETHING_NEW", DistributionStatus::Unknown),
+        ];
+
+        for (raw, expected) in cases {
+            let json = serde_json::json!({
+                "distribution_uuid": "dist_uuid_123",
+                "distribution_status": raw,
+                "transactions": []
+            });
+            let response: DistributionResponse = serde_json::from_value(json).unwrap();
+            assert_eq!(response.status(), expected, "status {raw}");
+            assert!(response.map_address_amount.is_empty());
+        }
+
+        // Creation responses carry no status and are treated as pending
+        let created: DistributionResponse = serde_json::from_value(serde_json::json!({
+            "distribution_uuid": "dist_uuid_123",
+            "map_address_amount": {"lq1address": 10.0},
+            "map_address_asset": {"lq1address": "asset_id"},
+            "asset_id": "asset_id"
+        }))
+        .unwrap();
+        assert_eq!(created.status(), DistributionStatus::Pending);
+        assert!(serde_json::to_value(&created)
+            .unwrap()
+            .get("distribution_status")
+            .is_none());
+    }
+
+    fn change_utxo(txid: &str, vout: u32) -> Unspent {
+        Unspent {
+            txid: txid.to_string(),
+            vout,
+            amount: 12.5,
+            asset: "change_asset_id".to_string(),
+            address: "change_address".to_string(),
+            spendable: true,
+            confirmations: Some(2),
+            scriptpubkey: None,
+            redeemscript: None,
+            witnessscript: None,
+            amountblinder: Some(
+                "3333333333333333333333333333333333333333333333333333333333333333".to_string(),
+            ),
+            assetblinder: Some(
+                "4444444444444444444444444444444444444444444444444444444444444444".to_string(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_confirm_distribution_request_from_change() {
+        let utxos = vec![change_utxo("dist_txid", 1), change_utxo("dist_txid", 3)];
+
+        let request = ConfirmDistributionRequest::from_change("dist-uuid", "dist_txid", &utxos)
+            .unwrap()
+            .with_details(serde_json::json!([{"vout": 0}]));
+
+        assert_eq!(request.tx_data.txid, "dist_txid");
+        assert_eq!(request.tx_data.details[0]["vout"], 0);
+        assert_eq!(request.change_data.len(), 2);
+        assert_eq!(request.change_data[1].vout, 3);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["change_data"][0]["amountblinder"],
+            "3333333333333333333333333333333333333333333333333333333333333333"
+        );
+        assert_eq!(
+            json["change_data"][0]["assetblinder"],
+            "4444444444444444444444444444444444444444444444444444444444444444"
+        );
+    }
+
+    #[test]
+    fn test_confirm_distribution_request_from_change_missing_blinders() {
+        let mut missing_amount = change_utxo("dist_txid", 1);
+        missing_amount.amountblinder = None;
+        let result =
+            ConfirmDistributionRequest::from_change("dist-uuid", "dist_txid", &[missing_amount]);
+        assert!(matches!(
+            result,
+            Err(crate::client::AmpError::Validation(ref msg)) if msg.contains("blinding data")
+        ));
+
+        let mut empty_asset = change_utxo("dist_txid", 1);
+        empty_asset.assetblinder = Some(String::new());
+        let result =
+            ConfirmDistributionRequest::from_change("dist-uuid", "dist_txid", &[empty_asset]);
+        assert!(result.is_err());
+
+        let foreign = change_utxo("other_txid", 0);
+        let result = ConfirmDistributionRequest::from_change("dist-uuid", "dist_txid", &[foreign]);
+        assert!(result.is_err());
+    }
 }
 
 /// Custom serialization module for Secret<String>
@@ -36,6 +36,13 @@ pub enum SignerError {
     #[error("Invalid mnemonic phrase: {0}")]
     InvalidMnemonic(String),
 
+    /// Invalid BIP32 derivation path errors
+    ///
+    /// Returned by `LwkSoftwareSigner::new_with_path` when the path cannot be
+    /// parsed (e.g. `m/84x/1h`) or has no components.
+    #[error("Invalid derivation path: {0}")]
+    InvalidDerivationPath(String),
+
     /// Hex string parsing and decoding errors
     ///
     /// This variant captures errors when parsing hex-encoded data including:
@@ -625,6 +625,27 @@ pub struct LwkSoftwareSigner {
     signer: lwk_signer::SwSigner,
     mnemonic: String,
     is_testnet: bool,
+    account_path: DerivationPath,
+}
+
+/// Number of receive and change indexes [`LwkSoftwareSigner::can_sign`]
+/// derives on each account before giving up on a script
+pub const OWNERSHIP_GAP_LIMIT: u32 = 20;
+
+/// BIP49 purpose field, for P2SH-wrapped segwit addresses
+const P2SH_WPKH_PURPOSE: u32 = 49;
+
+/// BIP84 purpose field, for native segwit addresses
+const WPKH_PURPOSE: u32 = 84;
+
+/// Account path used by [`LwkSoftwareSigner::derive_address`] unless overridden:
+/// BIP49 (P2SH-wrapped segwit) with Liquid's coin type 1776
+fn default_account_path() -> DerivationPath {
+    DerivationPath::from(vec![
+        ChildNumber::from_hardened_idx(P2SH_WPKH_PURPOSE).unwrap(),
+        ChildNumber::from_hardened_idx(1776).unwrap(),
+        ChildNumber::from_hardened_idx(0).unwrap(),
+    ])
 }
 
 impl LwkSoftwareSigner {
@@ -708,9 +729,100 @@ impl LwkSoftwareSigner {
             signer,
             mnemonic: mnemonic_phrase.to_string(),
             is_testnet: true,
+            account_path: default_account_path(),
         })
     }
 
+    /// Create a signer that derives addresses under a custom account path
+    ///
+    /// [`new`](Self::new) derives addresses under `m/49h/1776h/0h`. Use this
+    /// constructor to match a wallet created with a different purpose or
+    /// account, e.g. `m/84h/1776h/0h` or `m/49h/1776h/3h`. Addresses are
+    /// derived at `<derivation_path>/0/<index>`. Both `h` and `'` mark
+    /// hardened components.
+    ///
+    /// The purpose field picks the address type: `49h` derives P2SH-wrapped
+    /// segwit addresses and `84h` native segwit ones. Other purposes are
+    /// rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic_phrase` - A valid BIP39 mnemonic phrase
+    /// * `derivation_path` - The account-level BIP32 path
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `SignerError::InvalidDerivationPath` - If the path is malformed, empty,
+    ///   or its purpose is neither `49h` nor `84h`
+    /// - `SignerError::InvalidMnemonic` - If the mnemonic fails validation
+    /// - `SignerError::Lwk` - If LWK `SwSigner` creation fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use amp_rs::signer::{LwkSoftwareSigner, SignerError};
+    /// # fn main() -> Result<(), SignerError> {
+    /// let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let signer = LwkSoftwareSigner::new_with_path(mnemonic, "m/84h/1776h/0h")?;
+    /// assert_eq!(signer.derivation_path(), "m/84'/1776'/0'");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_path(
+        mnemonic_phrase: &str,
+        derivation_path: &str,
+    ) -> Result<Self, SignerError> {
+        let account_path: DerivationPath = derivation_path
+            .parse()
+            .map_err(|e| SignerError::InvalidDerivationPath(format!("'{derivation_path}': {e}")))?;
+        match account_path.as_ref().first() {
+            Some(ChildNumber::Hardened {
+                index: P2SH_WPKH_PURPOSE | WPKH_PURPOSE,
+            }) => {}
+            Some(purpose) => {
+                return Err(SignerError::InvalidDerivationPath(format!(
+                    "'{derivation_path}': purpose {purpose} is neither 49h (P2SH-wrapped segwit) nor 84h (native segwit)"
+                )));
+            }
+            None => {
+                return Err(SignerError::InvalidDerivationPath(format!(
+                    "'{derivation_path}' has no components"
+                )));
+            }
+        }
+
+        let mut signer = Self::new(mnemonic_phrase)?;
+        signer.account_path = account_path;
+        Ok(signer)
+    }
+
+    /// Returns the account path addresses are derived under, e.g. `m/49'/1776'/0'`
+    #[must_use]
+    pub fn derivation_path(&self) -> String {
+        format!("m/{}", self.account_path)
+    }
+
+    /// Returns the BIP32 fingerprint of the master key, as lowercase hex
+    ///
+    /// This is the `hdmasterfingerprint` an Elements wallet reports for
+    /// addresses derived from this signer's descriptor.
+    #[must_use]
+    pub fn fingerprint(&self) -> String {
+        self.signer.fingerprint().to_string()
+    }
+
+    /// Whether the account path's purpose is `84h`, so addresses are native
+    /// segwit rather than P2SH-wrapped
+    fn is_native_segwit(&self) -> bool {
+        matches!(
+            self.account_path.as_ref().first(),
+            Some(ChildNumber::Hardened {
+                index: WPKH_PURPOSE
+            })
+        )
+    }
+
     /// Generate a new signer, loading first mnemonic from file or creating new one
     ///
     /// This method implements the following logic:
@@ -842,6 +954,44 @@ impl LwkSoftwareSigner {
         Ok((mnemonic, signer_instance))
     }
 
+    /// Create a signer whose keys are fixed by `seed`, for snapshot tests
+    ///
+    /// The seed is used as the entropy of a 24-word mnemonic, so the signing
+    /// keys, the Slip77 master blinding key, every derived blinding key and
+    /// address, and (through RFC6979 nonces) every signature are a pure
+    /// function of `seed`. Two signers built from the same seed therefore sign
+    /// the same transaction to identical hex.
+    ///
+    /// Output blinding factors are not chosen by this signer: transactions are
+    /// blinded by the node's `blindrawtransaction`, which draws fresh
+    /// randomness. Snapshot tests should sign an already-blinded fixture or a
+    /// transaction with explicit outputs.
+    ///
+    /// Only available in unit tests and with the `testkit` feature; the
+    /// mnemonic is never written to `mnemonic.local.json`.
+    ///
+    /// # Errors
+    /// Returns `SignerError::Lwk` if signer creation fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use amp_rs::signer::{LwkSoftwareSigner, SignerError};
+    /// # fn main() -> Result<(), SignerError> {
+    /// let first = LwkSoftwareSigner::from_seed([7; 32])?;
+    /// let second = LwkSoftwareSigner::from_seed([7; 32])?;
+    /// assert_eq!(first.derive_address(Some(0))?, second.derive_address(Some(0))?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "testkit"))]
+    pub fn from_seed(seed: [u8; 32]) -> Result<Self, SignerError> {
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &seed)
+            .map_err(|e| SignerError::InvalidMnemonic(format!("Invalid seed entropy: {e}")))?;
+
+        Self::new(&mnemonic.to_string())
+    }
+
     /// Generate `WPkH` descriptor with Slip77 blinding for Liquid confidential addresses
     ///
     /// This method generates a single descriptor that covers both receive and change
@@ -849,6 +999,10 @@ impl LwkSoftwareSigner {
     /// wallet using the importdescriptors RPC call. This enables the wallet to scan
     /// and recognize addresses/UTXOs from the mnemonic.
     ///
+    /// The descriptor is built on [`Self::derivation_path`], the same account
+    /// [`Self::derive_address`] uses: P2SH-wrapped for a `49h` account and
+    /// native segwit for an `84h` one.
+    ///
     /// # Returns
     ///
     /// Returns a `Result` containing:
@@ -871,24 +1025,24 @@ impl LwkSoftwareSigner {
     /// ```
     pub fn get_wpkh_slip77_descriptor(&self) -> Result<String, SignerError> {
         tracing::debug!(
-            "Generating P2SH-wrapped WPkH Slip77 descriptor for Elements wallet import"
+            "Generating WPkH Slip77 descriptor for {} for Elements wallet import",
+            self.derivation_path()
         );
 
-        // Generate custom P2SH-wrapped segwit descriptor to match our address derivation
-        self.get_p2sh_wpkh_slip77_descriptor()
+        self.account_descriptor(!self.is_native_segwit())
     }
 
     /// Generate P2SH-wrapped `WPkH` descriptor with Slip77 blinding for Liquid confidential addresses
     ///
-    /// This method generates a P2SH-wrapped segwit descriptor that matches the addresses
-    /// generated by our `derive_address` method. This ensures consistency between the
-    /// descriptor imported into Elements and the addresses we derive.
+    /// This method generates a P2SH-wrapped segwit descriptor on the signer's
+    /// [`Self::derivation_path`], so for the default `49h` account it covers the
+    /// scripts `derive_address` produces.
     ///
-    /// The descriptor format is: `ct(slip77(...),elsh(elwpkh(...)))`
+    /// The descriptor format is: `ct(slip77(...),elsh(wpkh(...)))`
     /// - `ct()` - Confidential transaction wrapper
     /// - `slip77()` - Blinding key derivation
     /// - `elsh()` - Elements script hash (P2SH wrapper)
-    /// - `elwpkh()` - Elements witness public key hash (segwit)
+    /// - `wpkh()` - Witness public key hash (segwit), nested in `elsh()`
     ///
     /// # Returns
     ///
@@ -910,48 +1064,42 @@ impl LwkSoftwareSigner {
     /// # Ok(())
     /// # }
     /// ```
-    #[allow(clippy::cognitive_complexity)]
     pub fn get_p2sh_wpkh_slip77_descriptor(&self) -> Result<String, SignerError> {
-        tracing::debug!("Generating custom P2SH-wrapped WPkH Slip77 descriptor");
+        tracing::debug!("Generating P2SH-wrapped WPkH Slip77 descriptor");
+        self.account_descriptor(true)
+    }
 
-        // First get the native segwit descriptor from LWK
-        let native_descriptor = self.signer.wpkh_slip77_descriptor().map_err(|e| {
-            tracing::error!("Failed to generate native descriptor: {}", e);
-            SignerError::Lwk(format!("Failed to generate native descriptor: {e}"))
+    /// Builds `ct(slip77(..),elwpkh([fingerprint/path]xpub/<0;1>/*))` for the
+    /// signer's account path, or `ct(slip77(..),elsh(wpkh(..)))` when `p2sh` is set
+    fn account_descriptor(&self, p2sh: bool) -> Result<String, SignerError> {
+        let xpub = self.signer.derive_xpub(&self.account_path).map_err(|e| {
+            SignerError::Lwk(format!(
+                "Failed to derive xpub for {}: {e}",
+                self.derivation_path()
+            ))
         })?;
+        let blinding_key = self
+            .signer
+            .slip77_master_blinding_key()
+            .map_err(|e| SignerError::Lwk(format!("Failed to derive slip77 blinding key: {e}")))?;
+        let origin = format!(
+            "[{}/{}]",
+            self.signer.fingerprint(),
+            self.account_path.to_string().replace('\'', "h")
+        );
 
-        tracing::debug!("Native descriptor: {}", native_descriptor);
-
-        // Convert the native segwit descriptor to P2SH-wrapped
-        // The native descriptor looks like: ct(slip77(...),elwpkh([...]/84h/1h/0h]tpub.../<0;1>/*))#checksum
-        // We need to convert it to: ct(slip77(...),elsh(elwpkh([...]/49h/1h/0h]tpub.../<0;1>/*)))#checksum
-
-        let p2sh_descriptor = if native_descriptor.contains("elwpkh(") {
-            // Replace the derivation path from 84h (native segwit) to 49h (P2SH-wrapped segwit)
-            let with_p2sh_path = native_descriptor.replace("/84h/1h/0h]", "/49h/1h/0h]");
-
-            // Wrap the elwpkh with elsh() for P2SH
-            let wrapped = with_p2sh_path.replace("elwpkh(", "elsh(elwpkh(");
-
-            // Add the closing parenthesis for elsh() before the checksum
-            if let Some(checksum_pos) = wrapped.rfind("))#") {
-                let mut result = wrapped;
-                result.insert(checksum_pos + 1, ')');
-                result
-            } else {
-                // If no checksum, just add the closing parenthesis
-                wrapped.replace("))", ")))")
-            }
+        let key = format!("{origin}{xpub}/<0;1>/*");
+        let script = if p2sh {
+            format!("elsh(wpkh({key}))")
         } else {
-            return Err(SignerError::Lwk(
-                "Unexpected descriptor format - expected elwpkh".to_string(),
-            ));
+            format!("elwpkh({key})")
         };
+        let descriptor = format!("ct(slip77({blinding_key}),{script})");
+        let checksum = elements_miniscript::descriptor::checksum::desc_checksum(&descriptor)
+            .map_err(|e| SignerError::Lwk(format!("Failed to checksum descriptor: {e}")))?;
 
-        tracing::info!("Successfully generated P2SH-wrapped WPkH Slip77 descriptor");
-        tracing::debug!("P2SH descriptor: {}", p2sh_descriptor);
-
-        Ok(p2sh_descriptor)
+        tracing::debug!("Account descriptor: {}#{}", descriptor, checksum);
+        Ok(format!("{descriptor}#{checksum}"))
     }
 
     /// Generate `WPkH` descriptors with Slip77 blinding for Liquid confidential addresses
@@ -990,12 +1138,13 @@ impl LwkSoftwareSigner {
         Ok((descriptor.clone(), descriptor))
     }
 
-    /// Derive a P2SH-wrapped segwit receiving address from the signer's mnemonic
+    /// Derive a segwit receiving address from the signer's mnemonic
     ///
-    /// This method derives a P2SH-wrapped segwit receiving address from the signer's mnemonic
-    /// using BIP49 derivation paths. The address is suitable for receiving confidential assets
-    /// and can be used as a treasury address for asset operations. This provides a more complex
-    /// testing scenario with blinded P2SH transactions.
+    /// This method derives the address at `<account path>/0/<index>`, where the account
+    /// path is [`Self::derivation_path`] (`m/49'/1776'/0'` unless set with
+    /// [`Self::new_with_path`]). A `49h` account gives a P2SH-wrapped segwit address and
+    /// an `84h` account a native segwit one. The address is suitable for receiving
+    /// confidential assets and can be used as a treasury address for asset operations.
     ///
     /// # Arguments
     ///
@@ -1004,7 +1153,7 @@ impl LwkSoftwareSigner {
     /// # Returns
     ///
     /// Returns a `Result` containing:
-    /// - `Ok(String)` - Base58-encoded Liquid P2SH address (starts with 'v' on testnet)
+    /// - `Ok(String)` - Liquid testnet address (Base58 P2SH for `49h`, bech32 for `84h`)
     /// - `Err(SignerError)` - Address derivation error
     ///
     /// # Errors
@@ -1046,11 +1195,8 @@ impl LwkSoftwareSigner {
         let master_key = Xpriv::new_master(elements::bitcoin::Network::Regtest, &seed)
             .map_err(|e| SignerError::Lwk(format!("Failed to create master key: {e}")))?;
 
-        // Derive using BIP49 path: m/49'/1776'/0'/0/index (BIP49 for P2SH-wrapped segwit, 1776 is Liquid's coin type)
-        let derivation_path = DerivationPath::from(vec![
-            ChildNumber::from_hardened_idx(49).unwrap(), // BIP49 for P2SH-wrapped segwit
-            ChildNumber::from_hardened_idx(1776).unwrap(), // Liquid coin type
-            ChildNumber::from_hardened_idx(0).unwrap(),
+        // Derive <account path>/0/index (m/49'/1776'/0'/0/index unless overridden)
+        let derivation_path = self.account_path.extend([
             ChildNumber::from_normal_idx(0).unwrap(),
             ChildNumber::from_normal_idx(derivation_index).unwrap(),
         ]);
@@ -1069,8 +1215,12 @@ impl LwkSoftwareSigner {
         let blinding_key = derived_key.private_key;
         let blinding_pubkey = blinding_key.public_key(&secp);
 
-        // Create P2SH-wrapped segwit address for more complex testing scenario
-        let address = Address::p2shwpkh(&public_key, Some(blinding_pubkey), address_params);
+        // Native segwit for an 84h account, P2SH-wrapped segwit otherwise
+        let address = if self.is_native_segwit() {
+            Address::p2wpkh(&public_key, Some(blinding_pubkey), address_params)
+        } else {
+            Address::p2shwpkh(&public_key, Some(blinding_pubkey), address_params)
+        };
 
         let address_str = address.to_string();
         tracing::info!(
@@ -1082,6 +1232,97 @@ impl LwkSoftwareSigner {
         Ok(address_str)
     }
 
+    /// Reports, for each UTXO, whether this signer holds the key that spends it
+    ///
+    /// Each UTXO's `scriptpubkey` (or, when missing, the script of its address)
+    /// is compared against native and P2SH-wrapped segwit scripts derived from
+    /// the first [`OWNERSHIP_GAP_LIMIT`] receive and change indexes of both the
+    /// LWK descriptor account (`m/84h/1h/0h`) and [`Self::derivation_path`].
+    /// Outputs past the gap limit are reported as not owned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use amp_rs::signer::LwkSoftwareSigner;
+    /// # fn check(signer: &LwkSoftwareSigner, utxos: &[amp_rs::Unspent]) {
+    /// if let Some(index) = signer.can_sign(utxos).iter().position(|owned| !owned) {
+    ///     println!("Signer does not own input {index}");
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn can_sign(&self, utxos: &[Unspent]) -> Vec<bool> {
+        let owned_scripts = match self.owned_scripts(OWNERSHIP_GAP_LIMIT) {
+            Ok(scripts) => scripts,
+            Err(e) => {
+                tracing::warn!(
+                    "Cannot derive signer scripts to check UTXO ownership: {}",
+                    e
+                );
+                return vec![false; utxos.len()];
+            }
+        };
+
+        utxos
+            .iter()
+            .map(|utxo| {
+                let script_hex = utxo.scriptpubkey.clone().or_else(|| {
+                    utxo.address
+                        .parse::<Address>()
+                        .ok()
+                        .map(|address| hex::encode(address.script_pubkey().as_bytes()))
+                });
+                script_hex.is_some_and(|script| owned_scripts.contains(&script.to_lowercase()))
+            })
+            .collect()
+    }
+
+    /// Derives the hex scriptPubKeys this signer can spend, up to `gap_limit`
+    /// indexes per chain
+    fn owned_scripts(
+        &self,
+        gap_limit: u32,
+    ) -> Result<std::collections::HashSet<String>, SignerError> {
+        let mnemonic = Mnemonic::parse_in(Language::English, &self.mnemonic)
+            .map_err(|e| SignerError::InvalidMnemonic(format!("Failed to parse mnemonic: {e}")))?;
+        let secp = Secp256k1::new();
+        let master_key =
+            Xpriv::new_master(elements::bitcoin::Network::Regtest, &mnemonic.to_seed(""))
+                .map_err(|e| SignerError::Lwk(format!("Failed to create master key: {e}")))?;
+
+        // LWK's wpkh_slip77_descriptor account
+        let descriptor_account = DerivationPath::from(vec![
+            ChildNumber::from_hardened_idx(84).unwrap(),
+            ChildNumber::from_hardened_idx(1).unwrap(),
+            ChildNumber::from_hardened_idx(0).unwrap(),
+        ]);
+
+        let params = &AddressParams::LIQUID_TESTNET;
+        let mut scripts = std::collections::HashSet::new();
+        for account in [&descriptor_account, &self.account_path] {
+            for chain in 0..2 {
+                for index in 0..gap_limit {
+                    let path = account.extend([
+                        ChildNumber::from_normal_idx(chain).unwrap(),
+                        ChildNumber::from_normal_idx(index).unwrap(),
+                    ]);
+                    let derived_key = master_key
+                        .derive_priv(&secp, &path)
+                        .map_err(|e| SignerError::Lwk(format!("Failed to derive key: {e}")))?;
+                    let public_key = PublicKey::from(derived_key.private_key.public_key(&secp));
+
+                    for address in [
+                        Address::p2wpkh(&public_key, None, params),
+                        Address::p2shwpkh(&public_key, None, params),
+                    ] {
+                        scripts.insert(hex::encode(address.script_pubkey().as_bytes()));
+                    }
+                }
+            }
+        }
+        Ok(scripts)
+    }
+
     /// Check if this signer is configured for testnet/regtest networks
     ///
     /// This method returns the network configuration of the signer. For `LwkSoftwareSigner`,
@@ -1209,6 +1450,7 @@ impl LwkSoftwareSigner {
             signer,
             mnemonic: format!("elements_private_key:{private_key_wif}"),
             is_testnet: true,
+            account_path: default_account_path(),
         })
     }
 
@@ -1714,6 +1956,120 @@ mod tests {
     }
 
     #[test]
+    fn test_lwk_signer_descriptor_follows_derivation_path() {
+        use elements_miniscript::{ConfidentialDescriptor, DescriptorPublicKey};
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let secp = Secp256k1::new();
+
+        for (path, origin, wrapped) in [
+            ("m/49h/1776h/0h", "/49h/1776h/0h]", true),
+            ("m/84h/1776h/0h", "/84h/1776h/0h]", false),
+        ] {
+            let signer = LwkSoftwareSigner::new_with_path(mnemonic, path).unwrap();
+            let descriptor = signer.get_wpkh_slip77_descriptor().unwrap();
+            assert!(descriptor.contains(origin), "{path}: {descriptor}");
+            assert_eq!(descriptor.contains("elsh(wpkh("), wrapped, "{descriptor}");
+            assert_eq!(
+                signer.get_wpkh_slip77_descriptors().unwrap(),
+                (descriptor.clone(), descriptor.clone())
+            );
+
+            // The first receive script of the imported descriptor is the one
+            // derive_address hands out
+            let (body, _checksum) = descriptor.split_once('#').unwrap();
+            let receive: ConfidentialDescriptor<DescriptorPublicKey> =
+                body.replace("<0;1>", "0").parse().unwrap();
+            let descriptor_address = receive
+                .at_derivation_index(0)
+                .unwrap()
+                .address(&secp, &AddressParams::LIQUID_TESTNET)
+                .unwrap();
+            let derived: Address = signer.derive_address(Some(0)).unwrap().parse().unwrap();
+            assert_eq!(descriptor_address.script_pubkey(), derived.script_pubkey());
+        }
+    }
+
+    #[test]
+    fn test_lwk_signer_derivation_path_override() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let default_signer = LwkSoftwareSigner::new(mnemonic).unwrap();
+        assert_eq!(default_signer.derivation_path(), "m/49'/1776'/0'");
+
+        let explicit_default =
+            LwkSoftwareSigner::new_with_path(mnemonic, "m/49h/1776h/0h").unwrap();
+        assert_eq!(
+            explicit_default.derive_address(Some(0)).unwrap(),
+            default_signer.derive_address(Some(0)).unwrap()
+        );
+
+        let other_account = LwkSoftwareSigner::new_with_path(mnemonic, "m/49'/1776'/1'").unwrap();
+        assert_eq!(other_account.derivation_path(), "m/49'/1776'/1'");
+        assert_ne!(
+            other_account.derive_address(Some(0)).unwrap(),
+            default_signer.derive_address(Some(0)).unwrap()
+        );
+
+        let native = LwkSoftwareSigner::new_with_path(mnemonic, "m/84h/1776h/0h").unwrap();
+        let native_address: elements::Address =
+            native.derive_address(Some(0)).unwrap().parse().unwrap();
+        assert!(native_address.is_blinded());
+        assert!(native_address.script_pubkey().is_v0_p2wpkh());
+        let wrapped_address: elements::Address = default_signer
+            .derive_address(Some(0))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(wrapped_address.script_pubkey().is_p2sh());
+
+        for malformed in [
+            "m/84x/1h",
+            "m/",
+            "not a path",
+            "m",
+            "m/44h/1776h/0h",
+            "m/84/1776h/0h",
+        ] {
+            let result = LwkSoftwareSigner::new_with_path(mnemonic, malformed);
+            assert!(
+                matches!(result, Err(SignerError::InvalidDerivationPath(_))),
+                "{malformed} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_lwk_signer_can_sign_owned_and_foreign_utxos() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let signer = LwkSoftwareSigner::new(mnemonic).unwrap();
+        let foreign_signer = LwkSoftwareSigner::new(
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        )
+        .unwrap();
+
+        let utxo = |address: String| Unspent {
+            txid: "ab".repeat(32),
+            vout: 0,
+            amount: 1.0,
+            asset: "cd".repeat(32),
+            address,
+            spendable: true,
+            confirmations: Some(1),
+            scriptpubkey: None,
+            redeemscript: None,
+            witnessscript: None,
+            amountblinder: None,
+            assetblinder: None,
+        };
+        let owned = utxo(signer.derive_address(Some(3)).unwrap());
+        let foreign = utxo(foreign_signer.derive_address(Some(3)).unwrap());
+
+        assert_eq!(signer.can_sign(&[owned, foreign]), vec![true, false]);
+    }
+
+    #[test]
+    #[ignore = "Writes generated mnemonics to the shared mnemonic.local.json file in the working directory"]
     fn test_lwk_signer_generate_methods() {
         // Test generate_new method
         let result = LwkSoftwareSigner::generate_new();
@@ -1807,7 +2163,6 @@ mod tests {
 
         // Clean up any existing test files
         let _ = fs::remove_file(test_file);
-        let _ = fs::remove_file("mnemonic.local.json");
 
         // Test the behavior using MnemonicStorage directly to avoid file conflicts
         // Test 1: Create storage and generate first mnemonic
@@ -1842,7 +2197,6 @@ mod tests {
 
         // Cleanup
         let _ = fs::remove_file(test_file);
-        let _ = fs::remove_file("mnemonic.local.json");
     }
 
     #[test]
@@ -1858,7 +2212,6 @@ mod tests {
 
         // Clean up any existing test files
         let _ = fs::remove_file(test_file);
-        let _ = fs::remove_file("mnemonic.local.json");
 
         // Test using MnemonicStorage directly to avoid file conflicts
         let mut storage = MnemonicStorage::new();
@@ -1941,7 +2294,6 @@ mod tests {
 
         // Cleanup
         let _ = fs::remove_file(test_file);
-        let _ = fs::remove_file("mnemonic.local.json");
     }
 
     #[tokio::test]
@@ -2606,7 +2958,7 @@ mod tests {
     #[ignore = "Test isolation issue: generate_new_indexed writes to shared mnemonic.local.json file, causing race conditions when tests run in parallel"]
     fn test_network_configuration_validation() {
         // Test 1: Verify all signers are configured for testnet
-        let test_mnemonics = vec![
+        let test_mnemonics = [
             "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
             "legal winner thank year wave sausage worth useful legal winner thank yellow",
             "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
@@ -2777,6 +3129,70 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_from_seed_signer_is_reproducible() {
+        use elements::hashes::Hash;
+
+        async fn sign_fixture(seed: [u8; 32]) -> (String, String) {
+            let signer = LwkSoftwareSigner::from_seed(seed).unwrap();
+            let address = signer.derive_address(Some(0)).unwrap();
+            // Native segwit, so the sighash needs no redeem script
+            let script =
+                elements::Script::from(hex::decode(format!("0014{}", "33".repeat(20))).unwrap());
+            let asset = elements::AssetId::from_slice(&[0x11; 32]).unwrap();
+
+            let tx = elements::Transaction {
+                version: 2,
+                lock_time: elements::LockTime::ZERO,
+                input: vec![elements::TxIn {
+                    previous_output: elements::OutPoint::new(
+                        elements::Txid::from_byte_array([0x22; 32]),
+                        0,
+                    ),
+                    ..Default::default()
+                }],
+                output: vec![
+                    TxOut {
+                        asset: elements::confidential::Asset::Explicit(asset),
+                        value: elements::confidential::Value::Explicit(99_000),
+                        nonce: elements::confidential::Nonce::Null,
+                        script_pubkey: script.clone(),
+                        witness: elements::TxOutWitness::default(),
+                    },
+                    TxOut::new_fee(1_000, asset),
+                ],
+            };
+            let utxo = Unspent {
+                txid: tx.input[0].previous_output.txid.to_string(),
+                vout: 0,
+                amount: 0.001,
+                asset: asset.to_string(),
+                address: address.clone(),
+                spendable: true,
+                confirmations: Some(1),
+                scriptpubkey: Some(hex::encode(script.as_bytes())),
+                redeemscript: None,
+                witnessscript: None,
+                amountblinder: None,
+                assetblinder: None,
+            };
+
+            let signed = signer
+                .sign_transaction_with_utxos(&elements::encode::serialize_hex(&tx), &[utxo])
+                .await
+                .unwrap();
+            (address, signed)
+        }
+
+        let (first_address, first_signed) = sign_fixture([7; 32]).await;
+        let (second_address, second_signed) = sign_fixture([7; 32]).await;
+        assert_eq!(first_address, second_address);
+        assert_eq!(first_signed, second_signed);
+
+        let (other_address, _) = sign_fixture([8; 32]).await;
+        assert_ne!(first_address, other_address);
+    }
+
     #[tokio::test]
     async fn test_thread_safety_and_async_compatibility() {
         use std::sync::Arc;
@@ -2844,58 +3260,7 @@ mod tests {
             handle.await.expect("Signer creation task should complete");
         }
 
-        // Test 5: Test concurrent file operations (generate_new_indexed)
-        let file_handles: Vec<_> = (0..3)
-            .map(|index| {
-                task::spawn(async move {
-                    // Use different indices to avoid conflicts
-                    let actual_index = index + 10; // Offset to avoid conflicts with other tests
-                    let result = LwkSoftwareSigner::generate_new_indexed(actual_index);
-
-                    // Note: This might fail due to file system race conditions in concurrent tests
-                    // but the signer itself should handle this gracefully
-                    match result {
-                        Ok((mnemonic, signer)) => {
-                            assert!(
-                                !mnemonic.is_empty(),
-                                "Generated mnemonic should not be empty"
-                            );
-                            assert!(signer.is_testnet(), "Generated signer should be testnet");
-                            Ok(actual_index)
-                        }
-                        Err(e) => {
-                            // File system errors are acceptable in concurrent scenarios
-                            match e {
-                                SignerError::FileIo(_) | SignerError::Serialization(_) => {
-                                    Ok(actual_index)
-                                }
-                                other => Err(other),
-                            }
-                        }
-                    }
-                })
-            })
-            .collect();
-
-        // Wait for file operation tasks (allow some to fail due to concurrency)
-        let mut successful_file_ops = 0;
-        for handle in file_handles {
-            match handle.await.expect("File operation task should complete") {
-                Ok(_) => successful_file_ops += 1,
-                Err(e) => {
-                    // Log but don't fail the test for expected concurrency issues
-                    eprintln!("Expected concurrency error in file operations: {:?}", e);
-                }
-            }
-        }
-
-        // At least one file operation should succeed
-        assert!(
-            successful_file_ops > 0,
-            "At least one concurrent file operation should succeed"
-        );
-
-        // Test 6: Verify trait object compatibility (dynamic dispatch)
+        // Test 5: Verify trait object compatibility (dynamic dispatch)
         let signer: Box<dyn Signer> = Box::new(LwkSoftwareSigner::new(mnemonic).unwrap());
         let result = signer
             .sign_transaction("invalid_hex_characters_long_enough_for_test")
@@ -2909,7 +3274,7 @@ mod tests {
             ),
         }
 
-        // Test 7: Test Send + Sync bounds by moving signer across thread boundary
+        // Test 6: Test Send + Sync bounds by moving signer across thread boundary
         let signer = LwkSoftwareSigner::new(mnemonic).unwrap();
         let handle = task::spawn(async move {
             // Signer moved into async task (tests Send)
@@ -3347,8 +3712,8 @@ mod tests {
 
             // Note: This might not always fail depending on the system and user permissions
             // So we'll just verify the error handling works if it does fail
-            if result.is_err() {
-                match result.unwrap_err() {
+            if let Err(err) = result {
+                match err {
                     SignerError::FileIo(_) => {} // Expected error type
                     other => panic!("Expected FileIo error, got: {:?}", other),
                 }
@@ -3382,8 +3747,8 @@ mod tests {
 
         let result = storage.write_to_file_path(&long_path);
         // This might succeed or fail depending on the filesystem limits
-        if result.is_err() {
-            match result.unwrap_err() {
+        if let Err(err) = result {
+            match err {
                 SignerError::FileIo(_) => {} // Expected error type
                 other => panic!("Expected FileIo error, got: {:?}", other),
             }
@@ -0,0 +1,240 @@
+//! Regtest fixtures for integration tests
+//!
+//! Enabled with the `testkit` feature. Every helper here mines blocks and
+//! creates wallets on the connected node, so point it at a throwaway regtest
+//! instance only.
+
+use crate::client::{AmpError, ElementsRpc};
+use crate::signer::LwkSoftwareSigner;
+use elements::hashes::{sha256, Hash};
+
+/// Blocks a coinbase output must wait before it is spendable
+pub const COINBASE_MATURITY: u32 = 100;
+
+/// Upper bound on extra blocks mined while waiting for the treasury balance
+const MAX_FUNDING_BLOCKS: u32 = 200;
+
+/// Blocks mined per round once the first coinbase has matured
+const FUNDING_BATCH_BLOCKS: u32 = 10;
+
+/// A regtest wallet funded with the chain's policy asset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Treasury {
+    /// Name of the descriptor wallet on the node
+    pub wallet_name: String,
+    /// Address the funding coinbases were mined to
+    pub address: String,
+    /// Policy asset (L-BTC) id held by the treasury
+    pub asset_id: String,
+}
+
+/// Creates a descriptor wallet for `signer` and mines enough blocks to fund it
+///
+/// The wallet name is derived from the signer's descriptor, so calling this
+/// again with the same mnemonic reuses the existing wallet. Blocks are mined
+/// to a fresh wallet address until the spendable policy-asset balance reaches
+/// `amount`.
+///
+/// # Errors
+/// Returns a validation error if the node is not on a regtest chain or the
+/// balance is still short after mining the maximum number of blocks, and an
+/// RPC error if any node call fails
+///
+/// # Examples
+/// ```no_run
+/// # use amp_rs::{ElementsRpc, LwkSoftwareSigner};
+/// # use amp_rs::testkit::setup_funded_treasury;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let rpc = ElementsRpc::from_env()?;
+/// let (_, signer) = LwkSoftwareSigner::generate_new()?;
+/// let treasury = setup_funded_treasury(&rpc, &signer, 10.0).await?;
+/// println!("Treasury {} funded at {}", treasury.wallet_name, treasury.address);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn setup_funded_treasury(
+    rpc: &ElementsRpc,
+    signer: &LwkSoftwareSigner,
+    amount: f64,
+) -> Result<Treasury, AmpError> {
+    let chain = rpc.get_blockchain_info().await?.chain;
+    if !chain.contains("regtest") {
+        return Err(AmpError::validation(format!(
+            "setup_funded_treasury requires a regtest node, connected to '{chain}'"
+        )));
+    }
+
+    let (receive_descriptor, change_descriptor) = signer.get_wpkh_slip77_descriptors()?;
+    let fingerprint = sha256::Hash::hash(receive_descriptor.as_bytes()).to_string();
+    let wallet_name = format!("testkit_treasury_{}", &fingerprint[..8]);
+
+    rpc.setup_wallet_with_descriptors(&wallet_name, &receive_descriptor, &change_descriptor)
+        .await?;
+    let address = rpc.get_new_address(&wallet_name, None).await?;
+
+    let labels: std::collections::HashMap<String, String> = rpc
+        .rpc_call("dumpassetlabels", serde_json::json!([]))
+        .await?;
+    let asset_id = labels
+        .get("bitcoin")
+        .cloned()
+        .ok_or_else(|| AmpError::rpc("dumpassetlabels did not report a policy asset"))?;
+
+    tracing::info!(
+        "Funding testkit treasury {} with {} of {}",
+        wallet_name,
+        amount,
+        asset_id
+    );
+
+    let mut mined = 0;
+    let mut batch = COINBASE_MATURITY + 1;
+    loop {
+        rpc.generate_to_address(batch, &address).await?;
+        mined += batch;
+
+        let balance: f64 = rpc
+            .list_unspent_for_wallet(&wallet_name, Some(&asset_id))
+            .await?
+            .iter()
+            .map(|utxo| utxo.amount)
+            .sum();
+        if balance >= amount {
+            tracing::info!(
+                "Testkit treasury {} funded with {} after {} blocks",
+                wallet_name,
+                balance,
+                mined
+            );
+            break;
+        }
+        if mined >= COINBASE_MATURITY + 1 + MAX_FUNDING_BLOCKS {
+            return Err(AmpError::validation(format!(
+                "Treasury {wallet_name} holds {balance} after mining {mined} blocks, short of {amount}"
+            )));
+        }
+        batch = FUNDING_BATCH_BLOCKS;
+    }
+
+    Ok(Treasury {
+        wallet_name,
+        address,
+        asset_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+
+    const POLICY_ASSET: &str = "5ac9f65c0efcc4775e0baec4ec03abdde22473cd3cf33c0419ca290e0751b225";
+    const TREASURY_ADDRESS: &str = "el1qqfzfmtk4x2ezauhxq3l0fwfmz3sd4lq3n2jddnvhfqk8fjyxg5w3dsm8z5jhzh4lfpcjl9dp6xg9fj4p0nc2qgt7wn7n4hx6g";
+    const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn rpc_result(result: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({ "result": result, "error": null, "id": "amp-client" })
+    }
+
+    #[tokio::test]
+    async fn test_setup_funded_treasury_on_regtest() {
+        let server = MockServer::start();
+        let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+
+        server.mock(|when, then| {
+            when.method(POST).body_contains("\"getblockchaininfo\"");
+            then.status(200).json_body(rpc_result(serde_json::json!({
+                "chain": "elementsregtest",
+                "blocks": 0,
+                "headers": 0,
+                "bestblockhash": "00".repeat(32)
+            })));
+        });
+        let create_mock = server.mock(|when, then| {
+            when.method(POST).body_contains("\"createwallet\"");
+            then.status(200)
+                .json_body(rpc_result(serde_json::json!({ "name": "treasury" })));
+        });
+        let import_mock = server.mock(|when, then| {
+            when.method(POST).body_contains("\"importdescriptors\"");
+            then.status(200)
+                .json_body(rpc_result(serde_json::json!([{ "success": true }])));
+        });
+        server.mock(|when, then| {
+            when.method(POST).body_contains("\"loadwallet\"");
+            then.status(200)
+                .json_body(rpc_result(serde_json::json!({ "name": "treasury" })));
+        });
+        server.mock(|when, then| {
+            when.method(POST).body_contains("\"getwalletinfo\"");
+            then.status(200).json_body(rpc_result(
+                serde_json::json!({ "walletname": "treasury", "descriptors": true }),
+            ));
+        });
+        server.mock(|when, then| {
+            when.method(POST).body_contains("\"getnewaddress\"");
+            then.status(200)
+                .json_body(rpc_result(serde_json::json!(TREASURY_ADDRESS)));
+        });
+        server.mock(|when, then| {
+            when.method(POST).body_contains("\"dumpassetlabels\"");
+            then.status(200)
+                .json_body(rpc_result(serde_json::json!({ "bitcoin": POLICY_ASSET })));
+        });
+        let generate_mock = server.mock(|when, then| {
+            when.method(POST).json_body_partial(format!(
+                r#"{{"method": "generatetoaddress", "params": [101, "{TREASURY_ADDRESS}"]}}"#
+            ));
+            then.status(200).json_body(rpc_result(serde_json::json!([
+                "11".repeat(32),
+                "22".repeat(32)
+            ])));
+        });
+        server.mock(|when, then| {
+            when.method(POST).body_contains("\"listunspent\"");
+            then.status(200).json_body(rpc_result(serde_json::json!([{
+                "txid": "33".repeat(32),
+                "vout": 0,
+                "amount": 50.0,
+                "asset": POLICY_ASSET,
+                "address": TREASURY_ADDRESS,
+                "spendable": true,
+                "confirmations": 101,
+                "scriptpubkey": "0014".to_string() + &"44".repeat(20)
+            }])));
+        });
+
+        let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+        let treasury = setup_funded_treasury(&rpc, &signer, 10.0).await.unwrap();
+
+        assert!(treasury.wallet_name.starts_with("testkit_treasury_"));
+        assert_eq!(treasury.address, TREASURY_ADDRESS);
+        assert_eq!(treasury.asset_id, POLICY_ASSET);
+        assert_eq!(create_mock.hits(), 1);
+        assert_eq!(import_mock.hits(), 1);
+        assert_eq!(generate_mock.hits(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_setup_funded_treasury_rejects_non_regtest_chain() {
+        let server = MockServer::start();
+        let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+
+        server.mock(|when, then| {
+            when.method(POST).body_contains("\"getblockchaininfo\"");
+            then.status(200).json_body(rpc_result(serde_json::json!({
+                "chain": "liquidtestnet",
+                "blocks": 0,
+                "headers": 0,
+                "bestblockhash": "00".repeat(32)
+            })));
+        });
+
+        let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+        let err = setup_funded_treasury(&rpc, &signer, 10.0)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("regtest"));
+    }
+}
@@ -97,7 +97,7 @@ async fn test_get_changelog_live() {
 
     assert!(changelog.is_ok());
     let changelog_val = changelog.unwrap();
-    assert!(changelog_val.as_object().unwrap().len() > 0);
+    assert!(!changelog_val.as_object().unwrap().is_empty());
 }
 
 #[tokio::test]
@@ -117,7 +117,7 @@ async fn test_get_changelog_mock() {
 
     assert!(changelog.is_ok());
     let changelog_val = changelog.unwrap();
-    assert!(changelog_val.as_object().unwrap().len() > 0);
+    assert!(!changelog_val.as_object().unwrap().is_empty());
 
     // Cleanup
     cleanup_mock_test().await;
@@ -216,6 +216,71 @@ async fn test_get_asset_mock() {
     dotenvy::from_filename_override(".env").ok();
 }
 
+#[tokio::test]
+async fn test_get_asset_conditional_request_mock() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    let not_modified = server.mock(|when, then| {
+        when.method(GET)
+            .path("/assets/mock_asset_uuid")
+            .header("If-None-Match", "\"v1\"");
+        then.status(304);
+    });
+    let full = server.mock(|when, then| {
+        when.method(GET).path("/assets/mock_asset_uuid");
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("ETag", "\"v1\"")
+            .json_body(serde_json::json!({
+                "name": "Mock Asset",
+                "asset_uuid": "mock_asset_uuid",
+                "issuer": 1,
+                "asset_id": "mock_asset_id",
+                "reissuance_token_id": null,
+                "requirements": [],
+                "ticker": "MOCK",
+                "precision": 8,
+                "domain": "mock.com",
+                "pubkey": "mock_pubkey",
+                "is_registered": true,
+                "is_authorized": true,
+                "is_locked": false,
+                "issuer_authorization_endpoint": "https://example.com/authorize",
+                "transfer_restricted": true
+            }));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap()
+    .with_conditional_requests(true);
+    assert!(client.conditional_requests());
+
+    let first = client.get_asset("mock_asset_uuid").await.unwrap();
+    let second = client.get_asset("mock_asset_uuid").await.unwrap();
+    assert_eq!(second.asset_uuid, first.asset_uuid);
+    assert_eq!(second.name, "Mock Asset");
+    full.assert_hits(1);
+    not_modified.assert_hits(1);
+
+    // Without conditional requests every read fetches the full body.
+    let plain = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    assert!(!plain.conditional_requests());
+    plain.get_asset("mock_asset_uuid").await.unwrap();
+    plain.get_asset("mock_asset_uuid").await.unwrap();
+    full.assert_hits(3);
+    not_modified.assert_hits(1);
+
+    cleanup_mock_test().await;
+}
+
 #[tokio::test]
 async fn test_get_asset_memo_live() {
     dotenvy::from_filename_override(".env").ok();
@@ -320,11 +385,13 @@ async fn test_set_asset_memo_live() {
 }
 
 #[tokio::test]
+#[allow(clippy::assertions_on_constants)]
 async fn test_simple_memo_test() {
     assert!(true);
 }
 
 #[tokio::test]
+#[allow(clippy::assertions_on_constants)]
 async fn test_memo_simple() {
     assert!(true);
 }
@@ -509,7 +576,7 @@ async fn test_issue_asset_live() {
     // Test with curl to compare
     eprintln!("🔍 Testing with curl for comparison...");
     match std::process::Command::new("curl")
-        .args(&[
+        .args([
             "-s",
             "-o",
             "/dev/null",
@@ -1093,6 +1160,74 @@ async fn test_add_registered_user_mock() {
     setup_mock_test().await;
 }
 
+#[tokio::test]
+#[serial]
+async fn test_register_users_reports_per_user_results() {
+    // Setup mock test environment
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    for (id, name) in [(10, "Alice"), (12, "Carol")] {
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/registered_users/add")
+                .json_body_partial(format!(r#"{{"name": "{name}"}}"#));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({
+                    "id": id,
+                    "name": name,
+                    "gaid": null,
+                    "is_company": false,
+                    "authorization_url": null,
+                    "categories": [],
+                    "creator": 1
+                }));
+        });
+    }
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/registered_users/add")
+            .json_body_partial(r#"{"name": ""}"#);
+        then.status(400)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({"name": ["This field may not be blank."]}));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let users: Vec<amp_rs::model::RegisteredUserAdd> = ["Alice", "", "Carol"]
+        .iter()
+        .map(|name| amp_rs::model::RegisteredUserAdd {
+            name: (*name).to_string(),
+            gaid: None,
+            is_company: false,
+        })
+        .collect();
+
+    let report = client.register_users(&users).await.unwrap();
+    let registered: Vec<_> = report
+        .registered
+        .iter()
+        .map(|user| (user.id, user.name.as_str()))
+        .collect();
+    assert_eq!(registered, vec![(10, "Alice"), (12, "Carol")]);
+    assert_eq!(report.failed.len(), 1);
+    let (index, error) = &report.failed[0];
+    assert_eq!(*index, 1);
+    let amp_rs::Error::RequestFailed(message) = error else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert!(message.contains("400"), "{message}");
+    assert!(message.contains("may not be blank"), "{message}");
+
+    // Cleanup
+    cleanup_mock_test().await;
+}
+
 #[tokio::test]
 async fn test_get_categories_live() {
     dotenvy::from_filename_override(".env").ok();
@@ -1597,6 +1732,51 @@ async fn test_get_gaid_address_mock() {
     setup_mock_test().await;
 }
 
+#[tokio::test]
+async fn test_resolve_gaid_address_caches_mock() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    let address_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/gaids/GAbYScu6jkWUND2jo3L4KJxyvo55d/address");
+        then.status(200)
+            .json_body(serde_json::json!({"address": "mock_address"}));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/gaids/GA2HsrczzwaFzdJiw5NJM8P4iWKQh1/address");
+        then.status(200)
+            .json_body(serde_json::json!({"address": "", "error": "GAID is not registered"}));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let gaid = "GAbYScu6jkWUND2jo3L4KJxyvo55d";
+
+    assert_eq!(
+        client.resolve_gaid_address(gaid).await.unwrap(),
+        "mock_address"
+    );
+    // Clones share the cache, so the second lookup does not hit AMP
+    assert_eq!(
+        client.clone().resolve_gaid_address(gaid).await.unwrap(),
+        "mock_address"
+    );
+    address_mock.assert_hits(1);
+
+    let err = client
+        .resolve_gaid_address("GA2HsrczzwaFzdJiw5NJM8P4iWKQh1")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("GAID is not registered"), "{err}");
+
+    cleanup_mock_test().await;
+}
+
 #[tokio::test]
 async fn test_get_managers_live() {
     dotenvy::from_filename_override(".env").ok();
@@ -1725,7 +1905,7 @@ async fn test_create_asset_assignments_live() {
     let existing_users = client.get_registered_users().await.unwrap();
     let existing_user = existing_users
         .iter()
-        .find(|u| u.gaid.as_ref().map_or(false, |gaid| gaid == user_gaid));
+        .find(|u| u.gaid.as_ref().is_some_and(|gaid| gaid == user_gaid));
 
     let user_id = if let Some(user) = existing_user {
         println!(
@@ -1899,7 +2079,7 @@ async fn test_create_asset_assignments_live() {
         asset_uuid
     );
     let created_assignments = match client
-        .create_asset_assignments(&asset_uuid, &[request.clone()])
+        .create_asset_assignments(&asset_uuid, std::slice::from_ref(&request))
         .await
     {
         Ok(assignments) => {
@@ -2038,16 +2218,13 @@ async fn test_create_asset_assignments_mock() {
     );
     assert_eq!(assignment.amount, 100, "Amount should be an i64");
     assert_eq!(assignment.creator, 1, "Creator should be an i64");
-    assert_eq!(
-        assignment.ready_for_distribution, true,
+    assert!(
+        assignment.ready_for_distribution,
         "Ready for distribution should be a boolean"
     );
-    assert_eq!(
-        assignment.has_vested, true,
-        "Has vested should be a boolean"
-    );
-    assert_eq!(
-        assignment.is_distributed, false,
+    assert!(assignment.has_vested, "Has vested should be a boolean");
+    assert!(
+        !assignment.is_distributed,
         "Is distributed should be a boolean"
     );
 
@@ -3156,9 +3333,9 @@ async fn test_get_asset_assignment_mock() {
     assert_eq!(assignment.id, 10);
     assert_eq!(assignment.registered_user, 13);
     assert_eq!(assignment.amount, 100);
-    assert_eq!(assignment.ready_for_distribution, true);
-    assert_eq!(assignment.has_vested, true);
-    assert_eq!(assignment.is_distributed, false);
+    assert!(assignment.ready_for_distribution);
+    assert!(assignment.has_vested);
+    assert!(!assignment.is_distributed);
     assert_eq!(assignment.creator, 1);
     assert_eq!(
         assignment.gaid,
@@ -3469,6 +3646,110 @@ async fn test_delete_asset_assignment_mock() {
     setup_mock_test().await;
 }
 
+#[tokio::test]
+async fn test_delete_asset_assignment_rejects_distributed_mock() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/assets/mock_asset_uuid/assignments/10/delete");
+        then.status(400)
+            .json_body(serde_json::json!({"detail": "Cannot delete assignment"}));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/assets/mock_asset_uuid/assignments/10");
+        then.status(200).json_body(serde_json::json!({
+            "id": 10,
+            "registered_user": 13,
+            "amount": 100,
+            "receiving_address": "vjTvpDMn6kQ8JCRwdBJ3Zzn2HiU3uU8ALMvpqUMBpgwP4tcNFjovCsbAPjRpBFRPCd3AEN8Fd3khgtmT",
+            "distribution_uuid": "mock_distribution_uuid",
+            "ready_for_distribution": true,
+            "vesting_datetime": null,
+            "vesting_timestamp": null,
+            "has_vested": true,
+            "is_distributed": true,
+            "creator": 1,
+            "GAID": "GA3DS3emT12zDF4RGywBvJqZfhefNp"
+        }));
+    });
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/assets/mock_asset_uuid/assignments/create");
+        then.status(200).json_body(serde_json::json!([]));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+
+    let err = client
+        .delete_asset_assignment("mock_asset_uuid", "10")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        &err,
+        amp_rs::Error::AssignmentDistributed { assignment_id, .. } if assignment_id == "10"
+    ));
+    assert_eq!(err.kind(), "assignment_distributed");
+
+    // A rejected deletion leaves the assignment in place and creates nothing
+    let replacement = amp_rs::model::CreateAssetAssignmentRequest {
+        registered_user: 13,
+        amount: 50,
+        vesting_timestamp: None,
+        ready_for_distribution: false,
+    };
+    let err = client
+        .replace_asset_assignment("mock_asset_uuid", "10", replacement)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, amp_rs::Error::AssignmentDistributed { .. }));
+    create_mock.assert_hits(0);
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+async fn test_replace_asset_assignment_mock() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    let delete_mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/assets/mock_asset_uuid/assignments/7/delete");
+        then.status(200);
+    });
+    mocks::mock_create_asset_assignments(&server);
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let replacement = amp_rs::model::CreateAssetAssignmentRequest {
+        registered_user: 13,
+        amount: 100,
+        vesting_timestamp: None,
+        ready_for_distribution: true,
+    };
+
+    let assignment = client
+        .replace_asset_assignment("mock_asset_uuid", "7", replacement)
+        .await
+        .unwrap();
+
+    delete_mock.assert();
+    assert_eq!(assignment.id, 10);
+    assert_eq!(assignment.amount, 100);
+
+    cleanup_mock_test().await;
+}
+
 #[tokio::test]
 async fn test_lock_asset_assignment_mock() {
     // Setup mock test environment
@@ -3533,7 +3814,7 @@ async fn test_lock_asset_mock() {
     let result = client.lock_asset("mock_asset_uuid").await;
     assert!(result.is_ok());
     let asset = result.unwrap();
-    assert_eq!(asset.is_locked, true);
+    assert!(asset.is_locked);
 
     // Cleanup
     // Setup mock test environment
@@ -3557,7 +3838,7 @@ async fn test_unlock_asset_mock() {
     let result = client.unlock_asset("mock_asset_uuid").await;
     assert!(result.is_ok());
     let asset = result.unwrap();
-    assert_eq!(asset.is_locked, false);
+    assert!(!asset.is_locked);
 
     // Cleanup
     // Setup mock test environment
@@ -4643,7 +4924,7 @@ async fn test_get_asset_assignment_live() {
     let existing_users = client.get_registered_users().await.unwrap();
     let existing_user = existing_users
         .iter()
-        .find(|u| u.gaid.as_ref().map_or(false, |gaid| gaid == user_gaid));
+        .find(|u| u.gaid.as_ref().is_some_and(|gaid| gaid == user_gaid));
 
     let user_id = if let Some(user) = existing_user {
         println!(
@@ -4757,7 +5038,7 @@ async fn test_get_asset_assignment_live() {
 
     println!("Creating assignment for testing get_asset_assignment...");
     let created_assignments = client
-        .create_asset_assignments(&asset_uuid, &[request.clone()])
+        .create_asset_assignments(&asset_uuid, std::slice::from_ref(&request))
         .await
         .expect("Failed to create assignment for testing");
 
@@ -5376,7 +5657,10 @@ async fn test_burn_asset_validation_errors() {
     .unwrap();
 
     // Test invalid UUID format
-    let (_, signer) = amp_rs::signer::LwkSoftwareSigner::generate_new_indexed(300).unwrap();
+    let signer = amp_rs::signer::LwkSoftwareSigner::new(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    )
+    .unwrap();
 
     // We can't easily mock ElementsRpc here, so we'll test the validation that happens before RPC calls
     // Test empty asset UUID
@@ -5442,3 +5726,917 @@ async fn test_burn_asset_validation_errors() {
     // Cleanup
     cleanup_mock_test().await;
 }
+
+/// Mocks the Elements `listissuances` RPC for `mock_asset_id` with a single issuance
+fn mock_list_issuances_rpc(server: &MockServer, issuance: serde_json::Value) {
+    server.mock(|when, then| {
+        when.method(POST).path("/").json_body(serde_json::json!({
+            "jsonrpc": "1.0",
+            "id": "amp-client",
+            "method": "listissuances",
+            "params": ["mock_asset_id"]
+        }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "result": [issuance],
+                "error": null,
+                "id": "amp-client"
+            }));
+    });
+}
+
+#[tokio::test]
+#[serial]
+async fn test_verify_registration_mock() {
+    // Setup mock test environment
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    mocks::mock_get_asset(&server);
+    mocks::mock_get_asset_issuance_transactions(&server);
+
+    let rpc_server = MockServer::start();
+    mock_list_issuances_rpc(
+        &rpc_server,
+        serde_json::json!({
+            "txid": "mock_issuance_txid",
+            "vin": 0,
+            "asset": "mock_asset_id",
+            "assetamount": 21_000_000.0,
+            "token": "mock_token_id",
+            "tokenamount": 0.0,
+            "isreissuance": false
+        }),
+    );
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let elements_rpc =
+        amp_rs::ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+
+    let result = client
+        .verify_registration(&elements_rpc, "mock_asset_uuid")
+        .await;
+    assert!(result.is_ok(), "Expected matching issuance: {:?}", result);
+    assert!(result.unwrap());
+
+    // Cleanup
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_verify_registration_mismatch_mock() {
+    // Setup mock test environment
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    mocks::mock_get_asset(&server);
+    mocks::mock_get_asset_issuance_transactions(&server);
+
+    // Wrong txid, wrong amount and a reissuance token AMP doesn't know about
+    let rpc_server = MockServer::start();
+    mock_list_issuances_rpc(
+        &rpc_server,
+        serde_json::json!({
+            "txid": "other_issuance_txid",
+            "vin": 0,
+            "asset": "mock_asset_id",
+            "assetamount": 1_000.0,
+            "token": "mock_token_id",
+            "tokenamount": 1.0,
+            "isreissuance": false
+        }),
+    );
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let elements_rpc =
+        amp_rs::ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+
+    let result = client
+        .verify_registration(&elements_rpc, "mock_asset_uuid")
+        .await;
+    assert!(result.is_err());
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("issuance txid"), "{error}");
+    assert!(
+        error.contains("issued amount: AMP has 21000000 (2100000000000000 base units), chain has 1000 (100000000000 base units)"),
+        "{error}"
+    );
+    assert!(error.contains("reissuable"), "{error}");
+
+    // Cleanup
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_check_address_reuse_flags_previously_used_address() {
+    // Setup mock test environment
+    setup_mock_test().await;
+
+    let rpc_server = MockServer::start();
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "loadwallet"}"#);
+        then.status(200).json_body(serde_json::json!({
+            "result": null,
+            "error": {"code": -35, "message": "Wallet is already loaded."},
+            "id": "amp-client"
+        }));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/treasury")
+            .json_body_partial(r#"{"method": "listreceivedbyaddress"}"#);
+        then.status(200).json_body(serde_json::json!({
+            "result": [
+                {
+                    "address": "used_address",
+                    "amount": {"mock_asset_id": 5.0},
+                    "confirmations": 12,
+                    "label": "",
+                    "txids": ["previous_distribution_txid"]
+                },
+                {
+                    "address": "other_asset_address",
+                    "amount": {"bitcoin": 0.1},
+                    "confirmations": 3,
+                    "label": "",
+                    "txids": ["funding_txid"]
+                }
+            ],
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse("http://localhost:1/api").unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap()
+    .with_strict_address_reuse(true);
+    let elements_rpc =
+        amp_rs::ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+
+    let addresses = vec![
+        "fresh_address".to_string(),
+        "used_address".to_string(),
+        "other_asset_address".to_string(),
+        "used_address".to_string(),
+    ];
+    let reused = client
+        .check_address_reuse(&elements_rpc, "treasury", "mock_asset_id", &addresses)
+        .await
+        .unwrap();
+    assert_eq!(reused, vec!["used_address".to_string()]);
+
+    // Cleanup
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_wait_for_distribution_status_pending_to_confirmed() {
+    use amp_rs::model::DistributionStatus;
+    use std::time::Duration;
+
+    // Setup mock test environment
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    let distribution_path = "/assets/mock_asset_uuid/distributions/mock_distribution_uuid";
+    let distribution = |status: &str| {
+        serde_json::json!({
+            "distribution_uuid": "mock_distribution_uuid",
+            "distribution_status": status,
+            "transactions": []
+        })
+    };
+    let pending = server.mock(|when, then| {
+        when.method(GET).path(distribution_path);
+        then.status(200).json_body(distribution("UNCONFIRMED"));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+
+    let wait = client.wait_for_distribution_status(
+        "mock_asset_uuid",
+        "mock_distribution_uuid",
+        DistributionStatus::Confirmed,
+        Duration::from_secs(10),
+        Duration::from_millis(20),
+    );
+    let confirm_after_two_polls = async {
+        while pending.hits_async().await < 2 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        pending.delete_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path(distribution_path);
+            then.status(200).json_body(distribution("CONFIRMED"));
+        })
+    };
+    let (result, mut confirmed) = tokio::join!(wait, confirm_after_two_polls);
+
+    assert_eq!(result.unwrap().status(), DistributionStatus::Confirmed);
+    assert_eq!(confirmed.hits(), 1);
+
+    // A failed distribution ends the wait immediately
+    confirmed.delete();
+    server.mock(|when, then| {
+        when.method(GET).path(distribution_path);
+        then.status(200).json_body(distribution("FAILED"));
+    });
+    let error = client
+        .wait_for_distribution_status(
+            "mock_asset_uuid",
+            "mock_distribution_uuid",
+            DistributionStatus::Confirmed,
+            Duration::from_secs(10),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("failed while waiting"), "{error}");
+
+    // Cleanup
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_get_distribution_mock() {
+    // Setup mock test environment
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    mocks::mock_get_asset_distribution(&server);
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+
+    let distribution = client
+        .get_distribution("mock_asset_uuid", "mock_distribution_uuid")
+        .await
+        .unwrap();
+    assert_eq!(distribution.distribution_uuid, "mock_distribution_uuid");
+    assert_eq!(
+        distribution.status(),
+        amp_rs::model::DistributionStatus::Confirmed
+    );
+
+    let result = client.get_distribution("mock_asset_uuid", "").await;
+    assert!(result.is_err());
+
+    // Cleanup
+    cleanup_mock_test().await;
+}
+
+fn mock_rotation_token(server: &MockServer, password: &str, token: &str) {
+    let body = format!(r#"{{"username": "mock_user", "password": "{password}"}}"#);
+    let token = token.to_string();
+    server.mock(move |when, then| {
+        when.method(POST)
+            .path("/user/obtain_token")
+            .json_body_partial(body);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "token": token }));
+    });
+}
+
+#[tokio::test]
+#[serial]
+async fn test_rotate_password_verifies_new_credentials() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    mock_rotation_token(&server, "old_pass", "old_token");
+    mock_rotation_token(&server, "new_pass", "new_token");
+    let change_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/user/change_password")
+            .json_body_partial(r#"{"password": "new_pass"}"#);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "username": "mock_user",
+                "password": "new_pass",
+                "token": "change_token"
+            }));
+    });
+    // The check must authenticate with the token issued for the new password
+    let changelog_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/changelog")
+            .header("authorization", "token new_token");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({}));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    client
+        .rotate_password(
+            Secret::new("old_pass".to_string()),
+            Secret::new("new_pass".to_string()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(change_mock.hits(), 1);
+    assert_eq!(changelog_mock.hits(), 1);
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_rotate_password_stores_new_token_in_token_manager() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    mock_rotation_token(&server, "old_pass", "old_token");
+    mock_rotation_token(&server, "new_pass", "new_token");
+    server.mock(|when, then| {
+        when.method(POST).path("/user/change_password");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "username": "mock_user",
+                "password": "new_pass",
+                "token": "change_token"
+            }));
+    });
+    let ping_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/changelog")
+            .header("authorization", "token new_token");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({}));
+    });
+
+    env::set_var("AMP_API_BASE_URL", server.base_url());
+    let token_manager = Arc::new(
+        amp_rs::client::TokenManager::with_mock_token(
+            amp_rs::client::RetryConfig::for_tests(),
+            Url::parse(&server.base_url()).unwrap(),
+            "session_token".to_string(),
+        )
+        .unwrap(),
+    );
+    let client = ApiClient::with_token_manager(Arc::clone(&token_manager)).unwrap();
+    env::remove_var("AMP_API_BASE_URL");
+
+    client
+        .rotate_password(
+            Secret::new("old_pass".to_string()),
+            Secret::new("new_pass".to_string()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(token_manager.get_token().await.unwrap(), "new_token");
+    ping_mock.assert_hits(1);
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_rotate_password_reports_token_failure_after_change() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    mock_rotation_token(&server, "old_pass", "old_token");
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/user/obtain_token")
+            .json_body_partial(r#"{"password": "new_pass"}"#);
+        then.status(401)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "detail": "Invalid credentials" }));
+    });
+    server.mock(|when, then| {
+        when.method(POST).path("/user/change_password");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "username": "mock_user",
+                "password": "new_pass",
+                "token": "change_token"
+            }));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let err = client
+        .rotate_password(
+            Secret::new("old_pass".to_string()),
+            Secret::new("new_pass".to_string()),
+        )
+        .await
+        .unwrap_err();
+
+    match err {
+        amp_rs::Error::PasswordRotationFailed {
+            step,
+            password_changed,
+            ..
+        } => {
+            assert_eq!(step, amp_rs::PasswordRotationStep::ObtainToken);
+            assert!(password_changed);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_rotate_password_change_step_reports_whether_password_may_have_changed() {
+    setup_mock_test().await;
+
+    // An error status means the server kept the old password; an unreadable
+    // success reply may follow a change that was applied
+    for (status, body, expect_changed) in [
+        (400, r#"{"password": ["Too short"]}"#, false),
+        (200, "<html>gateway</html>", true),
+    ] {
+        let server = MockServer::start();
+        mock_rotation_token(&server, "old_pass", "old_token");
+        server.mock(|when, then| {
+            when.method(POST).path("/user/change_password");
+            then.status(status).body(body);
+        });
+
+        let client = ApiClient::with_mock_token(
+            Url::parse(&server.base_url()).unwrap(),
+            "mock_token".to_string(),
+        )
+        .unwrap();
+        let err = client
+            .rotate_password(
+                Secret::new("old_pass".to_string()),
+                Secret::new("new_pass".to_string()),
+            )
+            .await
+            .unwrap_err();
+
+        match err {
+            amp_rs::Error::PasswordRotationFailed {
+                step,
+                password_changed,
+                ..
+            } => {
+                assert_eq!(step, amp_rs::PasswordRotationStep::ChangePassword);
+                assert_eq!(password_changed, expect_changed, "status {status}");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_system_health_reports_each_component() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    let changelog_mock = server.mock(|when, then| {
+        when.method(GET).path("/changelog");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({}));
+    });
+    let rpc_server = MockServer::start();
+    rpc_server.mock(|when, then| {
+        when.method(POST).path("/");
+        then.status(503).body("node is starting");
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let rpc = amp_rs::ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+
+    let health = amp_rs::system_health(&client, &rpc).await;
+
+    assert!(health.amp.healthy);
+    assert!(health.amp.error.is_none());
+    assert!(!health.node.healthy);
+    assert!(health.node.error.as_deref().unwrap().contains("503"));
+    assert!(!health.is_ready());
+    assert_eq!(changelog_mock.hits(), 1);
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_list_pending_distributions_from_listtransactions() {
+    setup_mock_test().await;
+
+    let asset_id = "a".repeat(64);
+    let other_asset = "b".repeat(64);
+    let pending_txid = "1".repeat(64);
+    let confirmed_txid = "2".repeat(64);
+    let abandoned_txid = "3".repeat(64);
+
+    let rpc_server = MockServer::start();
+    let list_mock = rpc_server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/treasury")
+            .json_body_partial(r#"{"method": "listtransactions"}"#);
+        then.status(200).json_body(serde_json::json!({
+            "result": [
+                // Pending distribution with two recipient outputs
+                {"category": "send", "address": "addr1", "amount": -10.0, "asset": asset_id,
+                 "vout": 0, "confirmations": 0, "txid": pending_txid, "abandoned": false},
+                {"category": "send", "address": "addr2", "amount": -5.0, "asset": asset_id,
+                 "vout": 1, "confirmations": 0, "txid": pending_txid, "abandoned": false},
+                // Fee output in another asset
+                {"category": "send", "address": "addr3", "amount": -0.0001, "asset": other_asset,
+                 "vout": 2, "confirmations": 0, "txid": pending_txid},
+                // Confirmed and abandoned sends are not pending
+                {"category": "send", "address": "addr4", "amount": -1.0, "asset": asset_id,
+                 "vout": 0, "confirmations": 3, "txid": confirmed_txid},
+                {"category": "send", "address": "addr5", "amount": -1.0, "asset": asset_id,
+                 "vout": 0, "confirmations": 0, "txid": abandoned_txid, "abandoned": true},
+                // Change received back into the wallet
+                {"category": "receive", "address": "change", "amount": 85.0, "asset": asset_id,
+                 "vout": 3, "confirmations": 0, "txid": pending_txid}
+            ],
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    let abandon_mock = rpc_server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/treasury")
+            .json_body_partial(format!(
+                r#"{{"method": "abandontransaction", "params": ["{pending_txid}"]}}"#
+            ));
+        then.status(200).json_body(serde_json::json!({
+            "result": null,
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse("http://localhost:1").unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let rpc = amp_rs::ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+
+    let pending = client
+        .list_pending_distributions(&rpc, "treasury", &asset_id)
+        .await
+        .unwrap();
+
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].txid, pending_txid);
+    assert_eq!(
+        pending[0].outputs,
+        vec![
+            amp_rs::PendingOutput {
+                vout: 0,
+                address: "addr1".to_string(),
+                amount: 10.0,
+            },
+            amp_rs::PendingOutput {
+                vout: 1,
+                address: "addr2".to_string(),
+                amount: 5.0,
+            },
+        ]
+    );
+    assert_eq!(list_mock.hits(), 1);
+
+    client
+        .cancel_pending(&rpc, "treasury", &pending[0].txid)
+        .await
+        .unwrap();
+    assert_eq!(abandon_mock.hits(), 1);
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_create_distribution_with_reference_sends_reference() {
+    setup_mock_test().await;
+
+    let asset_uuid = "550e8400-e29b-41d4-a716-446655440000";
+    let server = MockServer::start();
+    let create_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/assets/{asset_uuid}/distributions/create"))
+            .json_body_partial(r#"{"reference": "settlement-42"}"#);
+        then.status(200).json_body(serde_json::json!({
+            "distribution_uuid": "dist-1",
+            "map_address_amount": {"lq1qaddress": 10.0},
+            "map_address_asset": {"lq1qaddress": "a".repeat(64)},
+            "asset_id": "a".repeat(64)
+        }));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let assignment = || amp_rs::model::AssetDistributionAssignment {
+        user_id: "user123".to_string(),
+        address: "lq1qaddress".to_string(),
+        amount: 10.0,
+    };
+
+    let distribution = client
+        .create_distribution_with_reference(
+            asset_uuid,
+            vec![assignment()],
+            Some("settlement-42".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(distribution.distribution_uuid, "dist-1");
+    create_mock.assert();
+
+    let too_long = "x".repeat(amp_rs::model::MAX_DISTRIBUTION_REFERENCE_LEN + 1);
+    let result = client
+        .create_distribution_with_reference(asset_uuid, vec![assignment()], Some(too_long))
+        .await;
+    assert!(matches!(result, Err(amp_rs::AmpError::Validation(_))));
+    assert_eq!(create_mock.hits(), 1);
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_with_endpoints_fails_over_when_primary_is_down() {
+    setup_mock_test().await;
+
+    let fallback = MockServer::start();
+    let changelog_mock = fallback.mock(|when, then| {
+        when.method(GET).path("/changelog");
+        then.status(200)
+            .json_body(serde_json::json!({"0.1.0": "initial"}));
+    });
+
+    // Nothing listens on port 1, so the primary refuses connections
+    let client = ApiClient::with_endpoints(
+        Url::parse("http://127.0.0.1:1").unwrap(),
+        vec![Url::parse(&fallback.base_url()).unwrap()],
+    )
+    .await
+    .unwrap();
+
+    let changelog = client.get_changelog().await.unwrap();
+    assert_eq!(changelog["0.1.0"], "initial");
+    changelog_mock.assert();
+
+    // An HTTP error from a reachable primary is returned without failing over
+    let primary = MockServer::start();
+    let rejected_mock = primary.mock(|when, then| {
+        when.method(GET).path("/changelog");
+        then.status(403).body("forbidden");
+    });
+    let client = ApiClient::with_endpoints(
+        Url::parse(&primary.base_url()).unwrap(),
+        vec![Url::parse(&fallback.base_url()).unwrap()],
+    )
+    .await
+    .unwrap();
+
+    assert!(client.get_changelog().await.is_err());
+    rejected_mock.assert();
+    changelog_mock.assert_hits(1);
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_current_supply_subtracts_burns() {
+    setup_mock_test().await;
+
+    let asset_id = "a".repeat(64);
+    let other_asset = "b".repeat(64);
+    let burn_txid = "1".repeat(64);
+    let distribution_txid = "2".repeat(64);
+
+    let rpc_server = MockServer::start();
+    let rpc_result = |result: serde_json::Value| serde_json::json!({"result": result, "error": null, "id": "amp-client"});
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "listissuances"}"#);
+        then.status(200).json_body(rpc_result(serde_json::json!([
+            {"txid": "3".repeat(64), "asset": asset_id, "assetamount": 1000.0, "isreissuance": false},
+            {"txid": "4".repeat(64), "asset": asset_id, "assetamount": 250.0, "isreissuance": true}
+        ])));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "listsinceblock", "params": ["", 1, true]}"#);
+        then.status(200).json_body(rpc_result(serde_json::json!({
+            "transactions": [
+                {"category": "send", "asset": asset_id, "amount": -40.0, "txid": burn_txid, "vout": 0},
+                {"category": "send", "asset": asset_id, "amount": -10.0, "txid": distribution_txid, "vout": 0, "address": "tlq1qqrecipient"},
+                {"category": "send", "asset": other_asset, "amount": -1.0, "txid": "5".repeat(64), "vout": 0},
+                {"category": "receive", "asset": asset_id, "amount": 5.0, "txid": "6".repeat(64), "vout": 1, "address": "tlq1qqwallet"}
+            ],
+            "removed": [],
+            "lastblock": "7".repeat(64)
+        })));
+    });
+    let gettransaction = |txid: &str, vout: serde_json::Value| {
+        rpc_server.mock(|when, then| {
+            when.method(POST).path("/").json_body_partial(format!(
+                r#"{{"method": "gettransaction", "params": ["{txid}", true, true]}}"#
+            ));
+            then.status(200).json_body(rpc_result(
+                serde_json::json!({ "decoded": { "vout": vout } }),
+            ));
+        })
+    };
+    let burn_mock = gettransaction(
+        &burn_txid,
+        serde_json::json!([
+            {"n": 0, "value": 40.0, "asset": asset_id, "scriptPubKey": {"type": "nulldata"}},
+            {"n": 1, "valuecommitment": "08aa", "scriptPubKey": {"type": "witness_v0_keyhash"}},
+            {"n": 2, "value": 0.0000025, "asset": other_asset, "scriptPubKey": {"type": "fee"}}
+        ]),
+    );
+    let distribution_mock = gettransaction(
+        &distribution_txid,
+        serde_json::json!([
+            {"n": 0, "valuecommitment": "09bb", "scriptPubKey": {"type": "witness_v0_keyhash"}}
+        ]),
+    );
+
+    let client = ApiClient::with_mock_token(
+        Url::parse("http://localhost:1").unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let rpc = amp_rs::ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+
+    let supply = client.current_supply(&rpc, &asset_id).await.unwrap();
+
+    assert_eq!(
+        supply,
+        amp_rs::SupplyReport {
+            issued: 1000.0,
+            reissued: 250.0,
+            burned: 40.0,
+            net: 1210.0,
+        }
+    );
+    burn_mock.assert();
+    // Sends to an address are not decoded
+    distribution_mock.assert_hits(0);
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+async fn test_check_transfer_restrictions_rejects_disallowed_recipient() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    // A transfer-restricted asset
+    mocks::mock_get_asset(&server);
+    server.mock(|when, then| {
+        when.method(GET).path("/registered_users");
+        then.status(200).json_body(serde_json::json!([
+            {"id": 1, "GAID": "GA3DS3emT12zDF4RGywBvJqZfhefNp", "is_company": false, "name": "Allowed", "categories": [10], "creator": 1},
+            {"id": 2, "GAID": "GA2HsrczzwaFzdJiw5NJM8P4iWKQh1", "is_company": false, "name": "Outsider", "categories": [20], "creator": 1},
+            {"id": 3, "GAID": null, "is_company": false, "name": "No GAID", "categories": [10], "creator": 1}
+        ]));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/categories");
+        then.status(200).json_body(serde_json::json!([
+            {"id": 10, "name": "Accredited", "description": null, "registered_users": [1, 3], "assets": ["mock_asset_uuid"]},
+            {"id": 20, "name": "Other", "description": null, "registered_users": [2], "assets": []}
+        ]));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let assignment = |user_id: &str| amp_rs::model::AssetDistributionAssignment {
+        user_id: user_id.to_string(),
+        address: format!("address_{user_id}"),
+        amount: 10.0,
+    };
+
+    client
+        .check_transfer_restrictions("mock_asset_uuid", &[assignment("1")])
+        .await
+        .unwrap();
+
+    let err = client
+        .check_transfer_restrictions(
+            "mock_asset_uuid",
+            &[
+                assignment("1"),
+                assignment("2"),
+                assignment("3"),
+                assignment("99"),
+            ],
+        )
+        .await
+        .unwrap_err();
+    let amp_rs::Error::ValidationErrors(disallowed) = &err else {
+        panic!("expected ValidationErrors, got {err:?}");
+    };
+    assert_eq!(
+        disallowed,
+        &vec![
+            "Recipient 2 (address_2) is not in a category for this asset".to_string(),
+            "Recipient 3 (address_3) has no GAID".to_string(),
+            "Recipient 99 (address_99) is not a registered user".to_string(),
+        ]
+    );
+    assert_eq!(err.kind(), "validation");
+
+    cleanup_mock_test().await;
+}
+
+#[tokio::test]
+async fn test_get_asset_holders_aggregates_ownerships() {
+    setup_mock_test().await;
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/assets/mock_asset_uuid/ownerships");
+        then.status(200).json_body(serde_json::json!([
+            {"owner": "Alice", "amount": 600, "GAID": "GA3DS3emT12zDF4RGywBvJqZfhefNp"},
+            {"owner": "Bob", "amount": 250, "GAID": "GA2HsrczzwaFzdJiw5NJM8P4iWKQh1"},
+            {"owner": "Alice", "amount": 100, "GAID": "GA3DS3emT12zDF4RGywBvJqZfhefNp"},
+            {"owner": "treasury", "amount": 50, "GAID": null},
+            {"owner": null, "amount": 7, "GAID": null}
+        ]));
+    });
+
+    let client = ApiClient::with_mock_token(
+        Url::parse(&server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+
+    let holders = client.get_asset_holders("mock_asset_uuid").await.unwrap();
+
+    assert_eq!(
+        holders.by_gaid,
+        vec![
+            ("GA2HsrczzwaFzdJiw5NJM8P4iWKQh1".to_string(), 250.0),
+            ("GA3DS3emT12zDF4RGywBvJqZfhefNp".to_string(), 700.0),
+        ]
+    );
+    assert_eq!(holders.without_gaid, vec![("treasury".to_string(), 50.0)]);
+    assert_eq!(amp_rs::total_supply_held(&holders), 1000.0);
+
+    cleanup_mock_test().await;
+}
@@ -28,11 +28,9 @@
 
 use amp_rs::signer::{LwkSoftwareSigner, Signer};
 use amp_rs::{ApiClient, ElementsRpc};
-use dotenvy;
 use serial_test::serial;
 use std::env;
 // use std::process::Command; // No longer needed - removed address.py dependency
-use tracing_subscriber;
 
 /// Helper function to conditionally print based on nocapture mode
 fn print_if_nocapture(msg: &str) {
@@ -42,6 +40,22 @@ fn print_if_nocapture(msg: &str) {
     }
 }
 
+/// BIP39 test vectors for tests that only need a distinct signer
+const TEST_MNEMONICS: [&str; 3] = [
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+];
+
+/// Helper function to create a signer from a fixed test mnemonic
+///
+/// Unlike `LwkSoftwareSigner::generate_new_indexed`, this does not write the
+/// mnemonic to `mnemonic.local.json`.
+fn test_signer(index: usize) -> Result<(String, LwkSoftwareSigner), Box<dyn std::error::Error>> {
+    let mnemonic = TEST_MNEMONICS[index];
+    Ok((mnemonic.to_string(), LwkSoftwareSigner::new(mnemonic)?))
+}
+
 /// Helper function to conditionally initialize tracing based on nocapture mode
 fn init_tracing_if_nocapture() {
     let should_print = std::env::args().any(|arg| arg == "--nocapture");
@@ -473,13 +487,13 @@ async fn test_environment_setup_and_infrastructure() -> Result<(), Box<dyn std::
         }
     }
 
-    // Task requirement: Generate LwkSoftwareSigner with new mnemonic for test isolation
-    print_if_nocapture("🔐 Generating LwkSoftwareSigner with new mnemonic for test isolation");
+    // Create the signer from a test mnemonic so no mnemonic is written to disk
+    print_if_nocapture("🔐 Creating LwkSoftwareSigner from a test mnemonic");
 
-    let (mnemonic, signer) = LwkSoftwareSigner::generate_new()
-        .map_err(|e| format!("Failed to generate LwkSoftwareSigner: {}", e))?;
+    let (mnemonic, signer) =
+        test_signer(0).map_err(|e| format!("Failed to create LwkSoftwareSigner: {}", e))?;
 
-    print_if_nocapture("✅ LwkSoftwareSigner generated successfully");
+    print_if_nocapture("✅ LwkSoftwareSigner created successfully");
     print_if_nocapture(&format!("   - Mnemonic: {}...", &mnemonic[..50]));
     print_if_nocapture(&format!("   - Testnet mode: {}", signer.is_testnet()));
 
@@ -740,12 +754,12 @@ async fn test_descriptor_wallet_setup() -> Result<(), Box<dyn std::error::Error>
 async fn test_lwk_signer_generation_and_isolation() -> Result<(), Box<dyn std::error::Error>> {
     print_if_nocapture("🔐 Testing LwkSoftwareSigner generation and isolation");
 
-    // Generate multiple signers to test isolation using indexed generation
-    let (mnemonic1, signer1) = LwkSoftwareSigner::generate_new_indexed(100)?;
-    let (mnemonic2, signer2) = LwkSoftwareSigner::generate_new_indexed(101)?;
-    let (mnemonic3, signer3) = LwkSoftwareSigner::generate_new_indexed(102)?;
+    // Create multiple signers from distinct test mnemonics to test isolation
+    let (mnemonic1, signer1) = test_signer(0)?;
+    let (mnemonic2, signer2) = test_signer(1)?;
+    let (mnemonic3, signer3) = test_signer(2)?;
 
-    println!("✅ Generated 3 signers successfully");
+    println!("✅ Created 3 signers successfully");
 
     // Verify they have different mnemonics (isolation)
     assert_ne!(mnemonic1, mnemonic2);
@@ -1201,7 +1215,7 @@ async fn test_end_to_end_distribution_workflow() -> Result<(), Box<dyn std::erro
     // Ensure the treasury address is added to the existing asset
     println!("🔧 Ensuring treasury address is configured for asset");
     match api_client
-        .add_asset_treasury_addresses(&asset_uuid, &vec![treasury_address.clone()])
+        .add_asset_treasury_addresses(&asset_uuid, std::slice::from_ref(&treasury_address))
         .await
     {
         Ok(_) => {
@@ -1397,9 +1411,10 @@ async fn test_end_to_end_distribution_workflow() -> Result<(), Box<dyn std::erro
         )
         .await
     {
-        Ok(()) => {
+        Ok(txids) => {
             let distribution_duration = distribution_start.elapsed();
             println!("🎉 distribute_asset completed successfully!");
+            println!("   - Transactions: {}", txids.join(", "));
             println!("   - Duration: {:?}", distribution_duration);
         }
         Err(e) => {
@@ -1865,14 +1880,14 @@ async fn test_distribution_assignment_creation() -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
-///
+//
 // Error scenario and edge case testing
-///
-/// This test implements task 7.5 requirements:
-/// - Test network failures, signing failures, and timeout conditions
-/// - Verify error handling for insufficient UTXOs and invalid addresses
-/// - Test duplicate distribution prevention and retry scenarios
-/// - Requirements: 5.1, 5.2, 5.3, 5.4, 5.5
+//
+// This test implements task 7.5 requirements:
+// - Test network failures, signing failures, and timeout conditions
+// - Verify error handling for insufficient UTXOs and invalid addresses
+// - Test duplicate distribution prevention and retry scenarios
+// - Requirements: 5.1, 5.2, 5.3, 5.4, 5.5
 
 /// Test network failure scenarios
 #[tokio::test]
@@ -1898,7 +1913,7 @@ async fn test_network_failure_scenarios() -> Result<(), Box<dyn std::error::Erro
     env::set_var("AMP_TESTS", "live");
 
     let api_client = ApiClient::new().await?;
-    let (mnemonic, signer) = LwkSoftwareSigner::generate_new_indexed(400)?;
+    let (mnemonic, signer) = test_signer(0)?;
 
     print_if_nocapture("✅ Test infrastructure setup complete");
     print_if_nocapture(&format!("   - Signer mnemonic: {}...", &mnemonic[..50]));
@@ -2131,11 +2146,8 @@ async fn test_signing_failure_scenarios() -> Result<(), Box<dyn std::error::Erro
     // Test 2: Invalid transaction hex (signer validation)
     println!("\n🧪 Test 2: Signer validation with invalid transaction hex");
 
-    let (mnemonic, valid_signer) = LwkSoftwareSigner::generate_new_indexed(401)?;
-    println!(
-        "   - Generated signer with mnemonic: {}...",
-        &mnemonic[..50]
-    );
+    let (mnemonic, valid_signer) = test_signer(0)?;
+    println!("   - Created signer with mnemonic: {}...", &mnemonic[..50]);
 
     // Test signer directly with invalid hex
     let invalid_hex_result = valid_signer.sign_transaction("invalid_hex_data").await;
@@ -2201,7 +2213,7 @@ async fn test_timeout_conditions() -> Result<(), Box<dyn std::error::Error>> {
     env::set_var("AMP_TESTS", "live");
 
     let _api_client = ApiClient::new().await?;
-    let (mnemonic, _signer) = LwkSoftwareSigner::generate_new_indexed(402)?;
+    let (mnemonic, _signer) = test_signer(0)?;
 
     println!("✅ Test infrastructure setup complete");
     println!("   - Signer mnemonic: {}...", &mnemonic[..50]);
@@ -2317,7 +2329,7 @@ async fn test_insufficient_utxos_and_invalid_addresses() -> Result<(), Box<dyn s
     env::set_var("AMP_TESTS", "live");
 
     let api_client = ApiClient::new().await?;
-    let (mnemonic, signer) = LwkSoftwareSigner::generate_new_indexed(403)?;
+    let (mnemonic, signer) = test_signer(0)?;
 
     println!("✅ Test infrastructure setup complete");
     println!("   - Signer mnemonic: {}...", &mnemonic[..50]);
@@ -2537,7 +2549,7 @@ async fn test_duplicate_distribution_and_retry_scenarios() -> Result<(), Box<dyn
     env::set_var("AMP_TESTS", "live");
 
     let api_client = ApiClient::new().await?;
-    let (mnemonic, signer) = LwkSoftwareSigner::generate_new_indexed(404)?;
+    let (mnemonic, signer) = test_signer(0)?;
 
     println!("✅ Test infrastructure setup complete");
     println!("   - Signer mnemonic: {}...", &mnemonic[..50]);
@@ -2644,12 +2656,12 @@ async fn test_duplicate_distribution_and_retry_scenarios() -> Result<(), Box<dyn
     // Test 5: Retryable error detection
     println!("\n🧪 Test 5: Retryable error detection");
 
-    let retryable_errors = vec![
+    let retryable_errors = [
         amp_rs::AmpError::rpc("Temporary RPC failure"),
         amp_rs::AmpError::rpc("Network connection lost"),
     ];
 
-    let non_retryable_errors = vec![
+    let non_retryable_errors = [
         amp_rs::AmpError::validation("Invalid data format"),
         amp_rs::AmpError::timeout("Confirmation timeout"),
     ];
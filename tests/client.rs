@@ -162,6 +162,26 @@ fn test_retry_config_builder_methods() {
     assert_eq!(config.max_delay_ms, 60000);
 }
 
+#[test]
+fn test_token_within_clock_skew_tolerance_is_valid() {
+    use amp_rs::model::TokenData;
+    use chrono::{Duration, Utc};
+
+    let config = RetryConfig::default();
+    assert_eq!(config.clock_skew_tolerance, Duration::seconds(30));
+
+    // Expired 10 seconds ago by our clock, but still inside the tolerance
+    let token_data = TokenData::new("token".to_string(), Utc::now() - Duration::seconds(10));
+    assert!(token_data.is_expired());
+    assert!(!token_data.is_expired_with_tolerance(config.clock_skew_tolerance));
+
+    let strict = config.with_clock_skew_tolerance(Duration::zero());
+    assert!(token_data.is_expired_with_tolerance(strict.clock_skew_tolerance));
+
+    let stale = TokenData::new("token".to_string(), Utc::now() - Duration::seconds(60));
+    assert!(stale.is_expired_with_tolerance(RetryConfig::default().clock_skew_tolerance));
+}
+
 #[test]
 #[serial]
 fn test_retry_config_partial_env_vars() {
@@ -244,6 +264,7 @@ fn test_retry_client_calculate_backoff_delay() {
         base_delay_ms: 1000,
         max_delay_ms: 10000,
         timeout_seconds: 10,
+        clock_skew_tolerance: chrono::Duration::seconds(30),
     };
     let retry_client = RetryClient::new(config);
 
@@ -274,6 +295,7 @@ fn test_retry_client_calculate_backoff_delay_with_small_max() {
         base_delay_ms: 1000,
         max_delay_ms: 2000, // Small max delay to test capping
         timeout_seconds: 10,
+        clock_skew_tolerance: chrono::Duration::seconds(30),
     };
     let retry_client = RetryClient::new(config);
 
@@ -342,6 +364,7 @@ fn test_retry_client_config_access() {
         base_delay_ms: 2000,
         max_delay_ms: 20000,
         timeout_seconds: 15,
+        clock_skew_tolerance: chrono::Duration::seconds(30),
     };
     let retry_client = RetryClient::new(config.clone());
 
@@ -424,7 +447,9 @@ async fn test_retry_client_server_error_retry() {
         TokenError::ObtainFailed {
             attempts,
             last_error,
+            status,
         } => {
+            assert_eq!(status, Some(500));
             assert_eq!(attempts, 2); // Should have tried max_attempts times
             assert!(last_error.contains("Server error: 500"));
         }
@@ -498,7 +523,9 @@ async fn test_retry_client_non_retryable_client_error() {
         TokenError::ObtainFailed {
             attempts,
             last_error,
+            status,
         } => {
+            assert_eq!(status, Some(404));
             assert_eq!(attempts, 1); // Should not retry
             assert!(last_error.contains("Client error: 404"));
         }
@@ -525,6 +552,7 @@ async fn test_retry_client_timeout_handling() {
         base_delay_ms: 100,
         max_delay_ms: 1000,
         timeout_seconds: 1, // Very short timeout
+        clock_skew_tolerance: chrono::Duration::seconds(30),
     };
     let retry_client = RetryClient::new(config);
     let url = format!("{}/timeout", server.base_url());
@@ -573,7 +601,9 @@ async fn test_retry_client_exhausted_retries() {
         TokenError::ObtainFailed {
             attempts,
             last_error,
+            status,
         } => {
+            assert_eq!(status, Some(500));
             assert_eq!(attempts, 2); // Should have tried max_attempts times
             assert!(last_error.contains("Server error: 500"));
         }
@@ -582,3 +612,534 @@ async fn test_retry_client_exhausted_retries() {
 
     always_fail_mock.assert_hits(2); // Should be called max_attempts times
 }
+
+#[tokio::test(start_paused = true)]
+#[serial]
+async fn test_token_manager_obtain_with_report_records_failures() {
+    use amp_rs::client::TokenManager;
+
+    env::set_var("AMP_USERNAME", "mock_user");
+    env::set_var("AMP_PASSWORD", "mock_pass");
+
+    let server = MockServer::start();
+    let mut failing_mock = server.mock(|when, then| {
+        when.method(POST).path("/user/obtain_token");
+        then.status(503).body("unavailable");
+    });
+
+    let config = RetryConfig {
+        max_attempts: 3,
+        base_delay_ms: 1_000,
+        max_delay_ms: 10_000,
+        // Far beyond any time the test advances, so only backoffs elapse
+        timeout_seconds: 3_600,
+        clock_skew_tolerance: chrono::Duration::seconds(30),
+    };
+    let manager = TokenManager::with_config_and_base_url(
+        config,
+        url::Url::parse(&server.base_url()).unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let obtain = tokio::spawn(async move { manager.obtain_with_report().await });
+
+    // Step the paused clock through the first backoff, then swap in a healthy
+    // endpoint before the second backoff is allowed to elapse
+    while failing_mock.hits() < 2 {
+        tokio::time::advance(StdDuration::from_millis(100)).await;
+    }
+    failing_mock.delete();
+    server.mock(|when, then| {
+        when.method(POST).path("/user/obtain_token");
+        then.status(200)
+            .json_body(serde_json::json!({"token": "fresh_token"}));
+    });
+    while !obtain.is_finished() {
+        tokio::time::advance(StdDuration::from_millis(100)).await;
+    }
+
+    let (result, report) = obtain.await.unwrap();
+
+    assert_eq!(result.unwrap(), "fresh_token");
+    assert_eq!(report.len(), 3);
+    for (index, outcome) in report[..2].iter().enumerate() {
+        assert_eq!(outcome.attempt, index as u32 + 1);
+        assert!(outcome.error.as_deref().unwrap().contains("503"));
+        assert!(outcome.delay.is_some());
+    }
+    assert_eq!(report[2].attempt, 3);
+    assert_eq!(report[2].error, None);
+    assert_eq!(report[2].delay, None);
+
+    env::remove_var("AMP_USERNAME");
+    env::remove_var("AMP_PASSWORD");
+}
+
+#[tokio::test]
+#[serial]
+async fn test_token_manager_get_token_after_dropped_request() {
+    use amp_rs::client::TokenManager;
+
+    env::set_var("AMP_USERNAME", "mock_user");
+    env::set_var("AMP_PASSWORD", "mock_pass");
+
+    let server = MockServer::start();
+    let mut slow_mock = server.mock(|when, then| {
+        when.method(POST).path("/user/obtain_token");
+        then.status(200)
+            .delay(StdDuration::from_secs(5))
+            .json_body(serde_json::json!({"token": "slow_token"}));
+    });
+
+    let manager = TokenManager::with_config_and_base_url(
+        RetryConfig::for_tests(),
+        url::Url::parse(&server.base_url()).unwrap(),
+    )
+    .await
+    .unwrap();
+
+    // Drop the obtain while it waits for the server, holding the token semaphore
+    let dropped = tokio::time::timeout(StdDuration::from_millis(200), manager.get_token()).await;
+    assert!(dropped.is_err());
+    slow_mock.assert_hits(1);
+
+    slow_mock.delete();
+    server.mock(|when, then| {
+        when.method(POST).path("/user/obtain_token");
+        then.status(200)
+            .json_body(serde_json::json!({"token": "fresh_token"}));
+    });
+
+    let token = tokio::time::timeout(StdDuration::from_secs(2), manager.get_token())
+        .await
+        .expect("semaphore or token lock was left held by the dropped future")
+        .unwrap();
+    assert_eq!(token, "fresh_token");
+
+    env::remove_var("AMP_USERNAME");
+    env::remove_var("AMP_PASSWORD");
+}
+
+#[test]
+fn test_endpoint_paths() {
+    use amp_rs::Endpoint;
+
+    let cases = [
+        (Endpoint::ObtainToken, "/user/obtain_token"),
+        (Endpoint::RefreshToken, "/user/refresh_token"),
+        (Endpoint::ChangePassword, "/user/change_password"),
+        (Endpoint::Changelog, "/changelog"),
+        (Endpoint::Assets, "/assets"),
+        (
+            Endpoint::Asset {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid",
+        ),
+        (Endpoint::IssueAsset, "/assets/issue"),
+        (
+            Endpoint::EditAsset {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/edit",
+        ),
+        (
+            Endpoint::RegisterAsset {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/register",
+        ),
+        (
+            Endpoint::RegisterAuthorizedAsset {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/register-authorized",
+        ),
+        (
+            Endpoint::DeleteAsset {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/delete",
+        ),
+        (
+            Endpoint::LockAsset {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/lock",
+        ),
+        (
+            Endpoint::UnlockAsset {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/unlock",
+        ),
+        (
+            Endpoint::AssetActivities {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/activities",
+        ),
+        (
+            Endpoint::AssetTransactions {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/txs",
+        ),
+        (
+            Endpoint::AssetTransaction {
+                asset_uuid: "asset-uuid",
+                txid: "txid",
+            },
+            "/assets/asset-uuid/txs/txid",
+        ),
+        (
+            Endpoint::AssetLostOutputs {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/lost-outputs",
+        ),
+        (
+            Endpoint::UpdateAssetBlinders {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/update-blinders",
+        ),
+        (
+            Endpoint::AssetBalance {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/balance",
+        ),
+        (
+            Endpoint::AssetSummary {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/summary",
+        ),
+        (
+            Endpoint::AssetOwnerships {
+                asset_uuid: "asset-uuid",
+                height: None,
+            },
+            "/assets/asset-uuid/ownerships",
+        ),
+        (
+            Endpoint::AssetOwnerships {
+                asset_uuid: "asset-uuid",
+                height: Some(1200),
+            },
+            "/assets/asset-uuid/ownerships/1200",
+        ),
+        (
+            Endpoint::AssetUtxos {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/utxos",
+        ),
+        (
+            Endpoint::BlacklistAssetUtxos {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/utxos/blacklist",
+        ),
+        (
+            Endpoint::WhitelistAssetUtxos {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/utxos/whitelist",
+        ),
+        (
+            Endpoint::AssetReissuances {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/reissuances",
+        ),
+        (
+            Endpoint::AssetMemo {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/memo",
+        ),
+        (
+            Endpoint::SetAssetMemo {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/memo/set",
+        ),
+        (
+            Endpoint::TreasuryAddresses {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/treasury-addresses",
+        ),
+        (
+            Endpoint::AddTreasuryAddresses {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/treasury-addresses/add",
+        ),
+        (
+            Endpoint::DeleteTreasuryAddresses {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/treasury-addresses/delete",
+        ),
+        (
+            Endpoint::AssetAssignments {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/assignments",
+        ),
+        (
+            Endpoint::CreateAssetAssignments {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/assignments/create",
+        ),
+        (
+            Endpoint::AssetAssignment {
+                asset_uuid: "asset-uuid",
+                assignment_id: "7",
+            },
+            "/assets/asset-uuid/assignments/7",
+        ),
+        (
+            Endpoint::DeleteAssetAssignment {
+                asset_uuid: "asset-uuid",
+                assignment_id: "7",
+            },
+            "/assets/asset-uuid/assignments/7/delete",
+        ),
+        (
+            Endpoint::LockAssetAssignment {
+                asset_uuid: "asset-uuid",
+                assignment_id: "7",
+            },
+            "/assets/asset-uuid/assignments/7/lock",
+        ),
+        (
+            Endpoint::UnlockAssetAssignment {
+                asset_uuid: "asset-uuid",
+                assignment_id: "7",
+            },
+            "/assets/asset-uuid/assignments/7/unlock",
+        ),
+        (
+            Endpoint::AssetDistributions {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/distributions",
+        ),
+        (
+            Endpoint::CreateDistribution {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/distributions/create",
+        ),
+        (
+            Endpoint::AssetDistribution {
+                asset_uuid: "asset-uuid",
+                distribution_uuid: "dist-uuid",
+            },
+            "/assets/asset-uuid/distributions/dist-uuid",
+        ),
+        (
+            Endpoint::ConfirmDistribution {
+                asset_uuid: "asset-uuid",
+                distribution_uuid: "dist-uuid",
+            },
+            "/assets/asset-uuid/distributions/dist-uuid/confirm",
+        ),
+        (
+            Endpoint::CancelDistribution {
+                asset_uuid: "asset-uuid",
+                distribution_uuid: "dist-uuid",
+            },
+            "/assets/asset-uuid/distributions/dist-uuid/cancel",
+        ),
+        (
+            Endpoint::ReissueRequest {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/reissue-request",
+        ),
+        (
+            Endpoint::ReissueConfirm {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/reissue-confirm",
+        ),
+        (
+            Endpoint::BurnRequest {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/burn-request",
+        ),
+        (
+            Endpoint::BurnConfirm {
+                asset_uuid: "asset-uuid",
+            },
+            "/assets/asset-uuid/burn-confirm",
+        ),
+        (Endpoint::BroadcastTransaction, "/tx/broadcast"),
+        (
+            Endpoint::BroadcastStatus { txid: "txid" },
+            "/tx/broadcast/txid",
+        ),
+        (Endpoint::RegisteredUsers, "/registered_users"),
+        (
+            Endpoint::RegisteredUser { user_id: 1 },
+            "/registered_users/1",
+        ),
+        (Endpoint::AddRegisteredUser, "/registered_users/add"),
+        (
+            Endpoint::EditRegisteredUser { user_id: 1 },
+            "/registered_users/1/edit",
+        ),
+        (
+            Endpoint::DeleteRegisteredUser { user_id: 1 },
+            "/registered_users/1/delete",
+        ),
+        (
+            Endpoint::RegisteredUserSummary { user_id: 1 },
+            "/registered_users/1/summary",
+        ),
+        (
+            Endpoint::RegisteredUserGaids { user_id: 1 },
+            "/registered_users/1/gaids",
+        ),
+        (
+            Endpoint::AddRegisteredUserGaid { user_id: 1 },
+            "/registered_users/1/gaids/add",
+        ),
+        (
+            Endpoint::SetDefaultRegisteredUserGaid { user_id: 1 },
+            "/registered_users/1/gaids/set-default",
+        ),
+        (
+            Endpoint::AddRegisteredUserCategories { user_id: 1 },
+            "/registered_users/1/categories/add",
+        ),
+        (
+            Endpoint::RemoveRegisteredUserCategories { user_id: 1 },
+            "/registered_users/1/categories/delete",
+        ),
+        (
+            Endpoint::GaidRegisteredUser { gaid: "GAID" },
+            "/gaids/GAID/registered_user",
+        ),
+        (
+            Endpoint::GaidBalance { gaid: "GAID" },
+            "/gaids/GAID/balance",
+        ),
+        (
+            Endpoint::GaidAssetBalance {
+                gaid: "GAID",
+                asset_uuid: "asset-uuid",
+            },
+            "/gaids/GAID/balance/asset-uuid",
+        ),
+        (
+            Endpoint::ValidateGaid { gaid: "GAID" },
+            "/gaids/GAID/validate",
+        ),
+        (
+            Endpoint::GaidAddress { gaid: "GAID" },
+            "/gaids/GAID/address",
+        ),
+        (Endpoint::Categories, "/categories"),
+        (Endpoint::Category { category_id: 2 }, "/categories/2"),
+        (Endpoint::AddCategory, "/categories/add"),
+        (
+            Endpoint::EditCategory { category_id: 2 },
+            "/categories/2/edit",
+        ),
+        (
+            Endpoint::DeleteCategory { category_id: 2 },
+            "/categories/2/delete",
+        ),
+        (
+            Endpoint::AddCategoryRegisteredUser {
+                category_id: 2,
+                user_id: 1,
+            },
+            "/categories/2/registered_users/1/add",
+        ),
+        (
+            Endpoint::RemoveCategoryRegisteredUser {
+                category_id: 2,
+                user_id: 1,
+            },
+            "/categories/2/registered_users/1/remove",
+        ),
+        (
+            Endpoint::AddCategoryAsset {
+                category_id: 2,
+                asset_uuid: "asset-uuid",
+            },
+            "/categories/2/assets/asset-uuid/add",
+        ),
+        (
+            Endpoint::RemoveCategoryAsset {
+                category_id: 2,
+                asset_uuid: "asset-uuid",
+            },
+            "/categories/2/assets/asset-uuid/remove",
+        ),
+        (Endpoint::Managers, "/managers"),
+        (Endpoint::CurrentManager, "/managers/me"),
+        (Endpoint::Manager { manager_id: 3 }, "/managers/3"),
+        (Endpoint::CreateManager, "/managers/create"),
+        (Endpoint::LockManager { manager_id: 3 }, "/managers/3/lock"),
+        (
+            Endpoint::UnlockManager { manager_id: 3 },
+            "/managers/3/unlock",
+        ),
+        (
+            Endpoint::ChangeManagerPassword { manager_id: 3 },
+            "/managers/3/change-password",
+        ),
+        (
+            Endpoint::AddManagerAsset {
+                manager_id: 3,
+                asset_uuid: "asset-uuid",
+            },
+            "/managers/3/assets/asset-uuid/add",
+        ),
+        (
+            Endpoint::RemoveManagerAsset {
+                manager_id: 3,
+                asset_uuid: "asset-uuid",
+            },
+            "/managers/3/assets/asset-uuid/remove",
+        ),
+    ];
+    for (endpoint, expected) in cases {
+        assert_eq!(endpoint.path(), expected, "{endpoint:?}");
+    }
+
+    // Identifiers are percent-encoded as single segments
+    let endpoint = Endpoint::AssetTransaction {
+        asset_uuid: "../assets",
+        txid: "a b/c",
+    };
+    assert_eq!(
+        endpoint.segments(),
+        vec!["assets", "../assets", "txs", "a b/c"]
+    );
+    assert_eq!(endpoint.path(), "/assets/..%2Fassets/txs/a%20b%2Fc");
+
+    let base = url::Url::parse("https://amp.example.com/api").unwrap();
+    assert_eq!(
+        Endpoint::Asset {
+            asset_uuid: "asset-uuid"
+        }
+        .url(&base)
+        .unwrap()
+        .as_str(),
+        "https://amp.example.com/api/assets/asset-uuid"
+    );
+    let not_a_base = url::Url::parse("mailto:amp@example.com").unwrap();
+    assert!(Endpoint::Assets.url(&not_a_base).is_err());
+}
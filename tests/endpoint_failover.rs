@@ -0,0 +1,63 @@
+//! Failover tests that need a live-like token environment
+//!
+//! These run in their own binary: they write `token.json` and initialise the
+//! global `TokenManager`, which would leak into the mock-based suites.
+
+use amp_rs::model::TokenData;
+use amp_rs::ApiClient;
+use chrono::{Duration, Utc};
+use httpmock::prelude::*;
+use std::env;
+use url::Url;
+
+#[tokio::test]
+async fn test_fallback_endpoint_never_uses_persisted_primary_token() {
+    let _ = ApiClient::force_cleanup_token_files().await;
+
+    // Nothing listens on port 1, so the primary refuses connections
+    env::set_var("AMP_TESTS", "live");
+    env::set_var("AMP_TOKEN_PERSISTENCE", "true");
+    env::set_var("AMP_USERNAME", "failover_user");
+    env::set_var("AMP_PASSWORD", "failover_password");
+    env::set_var("AMP_API_BASE_URL", "http://127.0.0.1:1");
+
+    let primary_token = TokenData::new("primary_token".to_string(), Utc::now() + Duration::days(1));
+    let persisted = serde_json::to_string_pretty(&primary_token).unwrap();
+    std::fs::write("token.json", &persisted).unwrap();
+
+    let fallback = MockServer::start();
+    let token_mock = fallback.mock(|when, then| {
+        when.method(POST).path("/user/obtain_token");
+        then.status(200)
+            .json_body(serde_json::json!({"token": "fallback_token"}));
+    });
+    let changelog_mock = fallback.mock(|when, then| {
+        when.method(GET)
+            .path("/changelog")
+            .header("authorization", "token fallback_token");
+        then.status(200)
+            .json_body(serde_json::json!({"0.1.0": "initial"}));
+    });
+
+    let client = ApiClient::with_endpoints(
+        Url::parse("http://127.0.0.1:1").unwrap(),
+        vec![Url::parse(&fallback.base_url()).unwrap()],
+    )
+    .await
+    .unwrap();
+
+    let changelog = client.get_changelog().await.unwrap();
+    assert_eq!(changelog["0.1.0"], "initial");
+    token_mock.assert();
+    changelog_mock.assert();
+
+    // The fallback's token stays in memory; the primary's file is untouched
+    assert_eq!(std::fs::read_to_string("token.json").unwrap(), persisted);
+
+    let _ = ApiClient::force_cleanup_token_files().await;
+    env::remove_var("AMP_TESTS");
+    env::remove_var("AMP_TOKEN_PERSISTENCE");
+    env::remove_var("AMP_USERNAME");
+    env::remove_var("AMP_PASSWORD");
+    env::remove_var("AMP_API_BASE_URL");
+}
@@ -166,6 +166,67 @@ async fn test_create_distribution() {
     assert!(!response.asset_id.is_empty());
 }
 
+#[tokio::test]
+async fn test_recorded_calls_capture_distribution_bodies() {
+    let client = MockApiClient::new();
+    let asset_uuid = client.get_assets().await.unwrap()[0].asset_uuid.clone();
+    client.clear_recorded_calls();
+
+    let address =
+        "vjU2i2EM2viGEzSywpStMPkTX9U9QSDsLSN63kJJYVpxKJZuxaph8v5r5Jf11aqnfBVdjSbrvcJ2pw26";
+    let assignments = vec![amp_rs::model::AssetDistributionAssignment {
+        user_id: "1".to_string(),
+        address: address.to_string(),
+        amount: 100.0,
+    }];
+
+    let distribution = client
+        .create_distribution(&asset_uuid, assignments)
+        .await
+        .unwrap();
+
+    let tx_data = amp_rs::model::AmpTxData {
+        details: serde_json::json!([]),
+        txid: "mock_distribution_txid".to_string(),
+    };
+    client
+        .confirm_distribution(
+            &asset_uuid,
+            &distribution.distribution_uuid,
+            tx_data,
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    client.expect_called("create_distribution", 1);
+    client.expect_called("confirm_distribution", 1);
+    // Asset lookups inside the mock are not counted as calls
+    client.expect_called("get_asset", 0);
+
+    let calls = client.recorded_calls();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].endpoint, "create_distribution");
+    assert_eq!(
+        calls[0].body.as_ref().unwrap()["assignments"][0]["address"],
+        address
+    );
+    assert_eq!(calls[1].endpoint, "confirm_distribution");
+    assert_eq!(
+        calls[1].body.as_ref().unwrap()["tx_data"]["txid"],
+        "mock_distribution_txid"
+    );
+}
+
+#[tokio::test]
+#[should_panic(expected = "expected `confirm_distribution` to be called 1 time(s)")]
+async fn test_expect_called_panics_on_mismatch() {
+    let client = MockApiClient::new();
+    client.get_assets().await.unwrap();
+
+    client.expect_called("confirm_distribution", 1);
+}
+
 #[tokio::test]
 async fn test_burn_request() {
     let client = MockApiClient::new();
@@ -278,7 +339,7 @@ async fn test_get_asset_reissuances() {
         .unwrap();
 
     // After reissue, asset should have reissuances
-    let reissuances = client.get_asset_reissuances(&asset_uuid).await.unwrap();
+    let reissuances = client.get_asset_reissuances(asset_uuid).await.unwrap();
     assert_eq!(
         reissuances.len(),
         1,
@@ -46,7 +46,7 @@ fn test_create_asset_assignment_request_defaults() {
     assert_eq!(deserialized.registered_user, 456);
     assert_eq!(deserialized.amount, 2000);
     assert_eq!(deserialized.vesting_timestamp, None);
-    assert_eq!(deserialized.ready_for_distribution, false); // Should default to false
+    assert!(!deserialized.ready_for_distribution); // Should default to false
 
     // Test with ready_for_distribution explicitly set to true
     let json_with_true = r#"{"registered_user":789,"amount":3000,"vesting_timestamp":1234567890,"ready_for_distribution":true}"#;
@@ -56,7 +56,7 @@ fn test_create_asset_assignment_request_defaults() {
     assert_eq!(deserialized_true.registered_user, 789);
     assert_eq!(deserialized_true.amount, 3000);
     assert_eq!(deserialized_true.vesting_timestamp, Some(1234567890));
-    assert_eq!(deserialized_true.ready_for_distribution, true);
+    assert!(deserialized_true.ready_for_distribution);
 }
 
 #[test]
@@ -285,3 +285,530 @@ fn test_update_blinders_request_with_different_vout() {
     assert!(json.contains("\"vout\":5"));
     assert!(json.contains("test_txid"));
 }
+
+#[test]
+fn test_verify_webhook_signature() {
+    use amp_rs::model::verify_webhook_signature;
+
+    let secret = b"amp-webhook-secret";
+    let payload = br#"{"event":"distribution_confirmed","asset_uuid":"550e8400-e29b-41d4-a716-446655440000"}"#;
+    let signature = "3e61441a501a48b72d1d40e7cc4fd4bdac5db4b66793723d5518d1cad01c3a48";
+
+    assert!(verify_webhook_signature(secret, payload, signature));
+    assert!(verify_webhook_signature(
+        secret,
+        payload,
+        &format!("sha256={}", signature.to_uppercase())
+    ));
+
+    // Widely published HMAC-SHA256 example vector
+    assert!(verify_webhook_signature(
+        b"key",
+        b"The quick brown fox jumps over the lazy dog",
+        "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+    ));
+}
+
+#[test]
+fn test_verify_webhook_signature_rejects_tampering() {
+    use amp_rs::model::verify_webhook_signature;
+
+    let secret = b"amp-webhook-secret";
+    let signature = "3e61441a501a48b72d1d40e7cc4fd4bdac5db4b66793723d5518d1cad01c3a48";
+    let tampered = br#"{"event":"distribution_confirmed","asset_uuid":"00000000-e29b-41d4-a716-446655440000"}"#;
+
+    assert!(!verify_webhook_signature(secret, tampered, signature));
+    assert!(!verify_webhook_signature(
+        b"wrong-secret",
+        br#"{"event":"distribution_confirmed","asset_uuid":"550e8400-e29b-41d4-a716-446655440000"}"#,
+        signature
+    ));
+    assert!(!verify_webhook_signature(secret, tampered, "not-hex"));
+    assert!(!verify_webhook_signature(
+        secret,
+        tampered,
+        &signature[..32]
+    ));
+    assert!(!verify_webhook_signature(secret, tampered, ""));
+}
+
+#[test]
+fn test_activity_kind_and_timestamp() {
+    use amp_rs::model::{Activity, ActivityKind};
+    use chrono::{TimeZone, Utc};
+
+    let activity = |activity_type: &str, datetime: &str| Activity {
+        activity_type: activity_type.to_string(),
+        datetime: datetime.to_string(),
+        description: String::new(),
+        txid: "txid".to_string(),
+        vout: 0,
+        blockheight: 100,
+        asset_blinder: String::new(),
+        amount_blinder: String::new(),
+        registered_user: None,
+        amount: 1,
+    };
+
+    let cases = [
+        ("issuance", ActivityKind::Issuance),
+        ("Reissuance", ActivityKind::Reissuance),
+        ("asset_reissuance", ActivityKind::Reissuance),
+        ("distribution", ActivityKind::Distribution),
+        ("BURN", ActivityKind::Burn),
+        ("assignment", ActivityKind::Assignment),
+        ("transfer", ActivityKind::Other),
+        ("", ActivityKind::Other),
+    ];
+    for (activity_type, expected) in cases {
+        assert_eq!(
+            activity(activity_type, "2024-01-01T00:00:00Z").kind(),
+            expected,
+            "{activity_type}"
+        );
+    }
+
+    let expected = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+    assert_eq!(
+        activity("issuance", "2024-01-10T09:00:00Z").timestamp(),
+        Some(expected)
+    );
+    assert_eq!(
+        activity("issuance", "2024-01-10T10:00:00+01:00").timestamp(),
+        Some(expected)
+    );
+    assert_eq!(
+        activity("issuance", "2024-01-10T09:00:00.000000").timestamp(),
+        Some(expected)
+    );
+    assert_eq!(activity("issuance", "not a date").timestamp(), None);
+}
+
+#[test]
+fn test_asset_id_validation() {
+    use amp_rs::model::AssetId;
+
+    let valid = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+    let asset_id: AssetId = valid.parse().unwrap();
+    assert_eq!(asset_id.as_str(), valid);
+    assert_eq!(asset_id.to_string(), valid);
+
+    let error = "6f0279e9".parse::<AssetId>().unwrap_err().to_string();
+    assert!(
+        error.contains("expected 64 hex characters, got 8"),
+        "{error}"
+    );
+
+    let non_hex = valid.replacen('6', "g", 1);
+    let error = non_hex.parse::<AssetId>().unwrap_err().to_string();
+    assert!(
+        error.contains("character 'g' at position 0 is not a hex digit"),
+        "{error}"
+    );
+
+    let error = valid
+        .to_uppercase()
+        .parse::<AssetId>()
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("must be lowercase"), "{error}");
+
+    // Serde goes through the same validation
+    let json = serde_json::to_string(&asset_id).unwrap();
+    assert_eq!(serde_json::from_str::<AssetId>(&json).unwrap(), asset_id);
+    assert!(serde_json::from_str::<AssetId>(r#""mock_asset_id""#).is_err());
+}
+
+#[test]
+fn test_txid_validation() {
+    use amp_rs::model::Txid;
+
+    let valid = "7ceabde8d7c1596b8b4af27286681dbde9c1551614b9788b6f84b9a3789d3184";
+    let txid: Txid = valid.parse().unwrap();
+    assert_eq!(txid.to_string(), valid);
+
+    // Explorer-style uppercase input is normalized
+    let upper: Txid = valid.to_uppercase().parse().unwrap();
+    assert_eq!(upper, txid);
+
+    let error = "mock_txid".parse::<Txid>().unwrap_err().to_string();
+    assert!(error.contains("Invalid txid 'mock_txid'"), "{error}");
+    assert!(
+        error.contains("expected 64 hex characters, got 9"),
+        "{error}"
+    );
+
+    let error = format!("{}z", &valid[..63])
+        .parse::<Txid>()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("character 'z' at position 63 is not a hex digit"),
+        "{error}"
+    );
+
+    assert!(serde_json::from_str::<Txid>(&format!(r#""{valid}""#)).is_ok());
+    assert!(serde_json::from_str::<Txid>(r#""abc123""#).is_err());
+}
+
+#[test]
+fn test_canonical_json_is_stable_and_sorted() {
+    use amp_rs::model::{canonical_json, AmpTxData, CreateAssetAssignmentRequest};
+    use amp_rs::ConfirmDistributionRequest;
+
+    let assignment = CreateAssetAssignmentRequest {
+        registered_user: 42,
+        amount: 1000,
+        vesting_timestamp: Some(1_700_000_000),
+        ready_for_distribution: true,
+    };
+    assert_eq!(
+        assignment.canonical_json(),
+        assignment.clone().canonical_json()
+    );
+    assert_eq!(
+        assignment.canonical_json(),
+        r#"{"amount":1000,"ready_for_distribution":true,"registered_user":42,"vesting_timestamp":1700000000}"#
+    );
+
+    let confirm = ConfirmDistributionRequest {
+        tx_data: AmpTxData {
+            details: serde_json::json!([{"vout": 0, "category": "send", "amount": -1.5}]),
+            txid: "ab".repeat(32),
+        },
+        change_data: Vec::new(),
+    };
+    let first = confirm.canonical_json();
+    assert_eq!(first, confirm.clone().canonical_json());
+    assert_eq!(first, canonical_json(&confirm));
+    assert!(first.starts_with(
+        r#"{"change_data":[],"tx_data":{"details":[{"amount":-1.5,"category":"send","vout":0}],"txid":"#
+    ));
+}
+
+#[test]
+fn test_reissuance_state() {
+    use amp_rs::model::{Reissuance, ReissuanceState};
+
+    let reissuance = |txid: serde_json::Value, block: serde_json::Value| -> Reissuance {
+        serde_json::from_value(serde_json::json!({
+            "txid": txid,
+            "vout": if txid.is_null() { serde_json::Value::Null } else { 0.into() },
+            "destination_address": "lq1qdestination",
+            "reissuance_amount": 1000,
+            "confirmed_in_block": block,
+            "created": "2024-01-10T09:00:00Z"
+        }))
+        .unwrap()
+    };
+    let txid = "a".repeat(64);
+
+    let requested = reissuance(serde_json::Value::Null, serde_json::Value::Null);
+    assert_eq!(requested.state(), ReissuanceState::Requested);
+    assert_eq!(requested.txid(), None);
+
+    let broadcast = reissuance(txid.clone().into(), "".into());
+    assert_eq!(broadcast.state(), ReissuanceState::Broadcast);
+    assert_eq!(broadcast.txid(), Some(txid.clone()));
+
+    let confirmed = reissuance(txid.clone().into(), "b".repeat(64).into());
+    assert_eq!(confirmed.state(), ReissuanceState::Confirmed);
+    assert_eq!(confirmed.txid(), Some(txid));
+}
+
+#[test]
+fn test_elements_output_serialization() {
+    use amp_rs::ElementsOutput;
+
+    let asset = "a".repeat(64);
+    let outputs = vec![
+        ElementsOutput::new("lq1qrecipient", 1.5, asset.clone()),
+        ElementsOutput::new("burn", 0.000_000_01, asset.clone()),
+    ];
+
+    assert_eq!(
+        serde_json::to_string(&outputs[0]).unwrap(),
+        format!(r#"{{"lq1qrecipient":"1.50000000","asset":"{asset}"}}"#)
+    );
+    assert_eq!(
+        serde_json::to_value(&outputs).unwrap(),
+        serde_json::json!([
+            {"lq1qrecipient": "1.50000000", "asset": asset},
+            {"burn": "0.00000001", "asset": asset}
+        ])
+    );
+}
+
+#[test]
+fn test_validate_gaid_response_reason() {
+    use amp_rs::model::ValidateGaidResponse;
+
+    let invalid: ValidateGaidResponse = serde_json::from_value(serde_json::json!({
+        "is_valid": false,
+        "error": "GAID checksum mismatch",
+        "error_code": "invalid_checksum"
+    }))
+    .unwrap();
+    assert!(!invalid.is_valid());
+    assert_eq!(invalid.reason(), Some("GAID checksum mismatch"));
+    assert_eq!(invalid.error_code.as_deref(), Some("invalid_checksum"));
+
+    let aliased: ValidateGaidResponse =
+        serde_json::from_value(serde_json::json!({"is_valid": false, "reason": "Unknown GAID"}))
+            .unwrap();
+    assert_eq!(aliased.reason(), Some("Unknown GAID"));
+    assert_eq!(aliased.error_code, None);
+
+    let valid: ValidateGaidResponse =
+        serde_json::from_value(serde_json::json!({"is_valid": true, "error": null})).unwrap();
+    assert!(valid.is_valid());
+    assert_eq!(valid.reason(), None);
+}
+
+#[test]
+fn test_classify_confidential_testnet_address() {
+    use amp_rs::model::{classify_address, AddressClass, AddressType, ElementsNetwork};
+
+    let segwit = "tlq1qqfttx29npj9ltqu7yszcw3u8jsytmvmzg8wfctnuvx065y4jjgyk0g7xk8hy5jwe72hnkwqzjar5f7afysty56vff0m9nk7h5";
+    assert_eq!(
+        classify_address(segwit, ElementsNetwork::LiquidTestnet).unwrap(),
+        AddressClass {
+            confidential: true,
+            address_type: AddressType::P2wpkh
+        }
+    );
+
+    // Base58 confidential addresses start with 'v' on testnet
+    let wrapped =
+        "vjTwB2nhJKD7nKNZSjMc83NYPaGKF4v8CcDZn11yx8gnZiUDbG673Em5RUGrEVRE7yPVVVUn8mgXif7S";
+    assert_eq!(
+        classify_address(wrapped, ElementsNetwork::LiquidTestnet).unwrap(),
+        AddressClass {
+            confidential: true,
+            address_type: AddressType::P2sh
+        }
+    );
+
+    let err = classify_address(segwit, ElementsNetwork::Liquid).unwrap_err();
+    assert!(matches!(err, amp_rs::AmpError::Validation(_)));
+}
+
+#[test]
+fn test_classify_unconfidential_testnet_address() {
+    use amp_rs::model::{classify_address, AddressClass, AddressType, ElementsNetwork};
+
+    assert_eq!(
+        classify_address(
+            "tex1q50rtrmj2f8vl9tem8qpfw36ylw5jg9j24kmnvl",
+            ElementsNetwork::LiquidTestnet
+        )
+        .unwrap(),
+        AddressClass {
+            confidential: false,
+            address_type: AddressType::P2wpkh
+        }
+    );
+    assert_eq!(
+        classify_address(
+            "Fk6gckZbtkPxRBFdWoF4xkvgDk6GzvYpVd",
+            ElementsNetwork::LiquidTestnet
+        )
+        .unwrap(),
+        AddressClass {
+            confidential: false,
+            address_type: AddressType::P2pkh
+        }
+    );
+    assert!(classify_address("not-an-address", ElementsNetwork::LiquidTestnet).is_err());
+    assert_eq!(
+        ElementsNetwork::from_chain("liquidtestnet"),
+        ElementsNetwork::LiquidTestnet
+    );
+}
+
+#[test]
+fn test_asset_summary_diff_reports_supply_change() {
+    use amp_rs::model::{AssetSummary, AssetSummaryChange, AssetSummaryField};
+
+    let summary = |reissued: i64, burned: i64, registered_users: i64| -> AssetSummary {
+        serde_json::from_value(serde_json::json!({
+            "asset_id": "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189",
+            "reissuance_token_id": null,
+            "issued": 1000,
+            "reissued": reissued,
+            "assigned": 400,
+            "distributed": 300,
+            "burned": burned,
+            "blacklisted": 0,
+            "registered_users": registered_users,
+            "active_registered_users": 5,
+            "active_green_subaccounts": 5,
+            "reissuance_tokens": 1
+        }))
+        .unwrap()
+    };
+
+    let before = summary(0, 0, 10);
+    let after = summary(500, 50, 12);
+    let diff = before.diff(&after);
+
+    assert_eq!(
+        diff.changes,
+        vec![
+            AssetSummaryChange {
+                field: AssetSummaryField::Reissued,
+                before: 0,
+                after: 500
+            },
+            AssetSummaryChange {
+                field: AssetSummaryField::Burned,
+                before: 0,
+                after: 50
+            },
+            AssetSummaryChange {
+                field: AssetSummaryField::RegisteredUsers,
+                before: 10,
+                after: 12
+            },
+        ]
+    );
+    assert_eq!(diff.supply_delta(), 450);
+    assert_eq!(
+        diff.get(AssetSummaryField::RegisteredUsers)
+            .unwrap()
+            .delta(),
+        2
+    );
+    assert!(diff.get(AssetSummaryField::Issued).is_none());
+    assert!(diff.reissuance_token_id.is_none());
+
+    assert!(before.diff(&summary(0, 0, 10)).is_empty());
+}
+
+#[test]
+fn test_distribution_tx_data_with_inputs() {
+    use amp_rs::model::{DistributionTxData, DistributionTxInput, Unspent};
+
+    let utxo = |txid: &str, vout: u32, asset: &str, blinded: bool| Unspent {
+        txid: txid.to_string(),
+        vout,
+        amount: 1.0,
+        asset: asset.to_string(),
+        address: "address".to_string(),
+        spendable: true,
+        confirmations: Some(6),
+        scriptpubkey: None,
+        redeemscript: None,
+        witnessscript: None,
+        amountblinder: blinded.then(|| "aa".repeat(32)),
+        assetblinder: blinded.then(|| "bb".repeat(32)),
+    };
+    let mut tx_data: DistributionTxData = serde_json::from_value(serde_json::json!({
+        "txid": "distribution_txid",
+        "details": {"txid": "distribution_txid", "confirmations": 2, "hex": "0200"}
+    }))
+    .unwrap();
+    assert!(tx_data.inputs.is_empty());
+    assert!(serde_json::to_value(&tx_data)
+        .unwrap()
+        .get("inputs")
+        .is_none());
+
+    tx_data.with_inputs(&[
+        utxo(&"ab".repeat(32), 0, "asset_id", true),
+        utxo(&"bc".repeat(32), 2, "lbtc_id", false),
+    ]);
+
+    assert_eq!(
+        tx_data.inputs,
+        vec![
+            DistributionTxInput {
+                txid: "ab".repeat(32),
+                vout: 0,
+                asset: "asset_id".to_string(),
+                amountblinder: Some("aa".repeat(32)),
+                assetblinder: Some("bb".repeat(32)),
+            },
+            DistributionTxInput {
+                txid: "bc".repeat(32),
+                vout: 2,
+                asset: "lbtc_id".to_string(),
+                amountblinder: None,
+                assetblinder: None,
+            },
+        ]
+    );
+    let json = serde_json::to_value(&tx_data).unwrap();
+    assert_eq!(json["inputs"][0]["vout"], 0);
+    assert!(json["inputs"][1].get("assetblinder").is_none());
+}
+
+#[test]
+fn test_amp_amount_conversions_across_precisions() {
+    use amp_rs::model::{amp_amount_to_node, node_amount_to_amp, NODE_AMOUNT_PRECISION};
+
+    assert_eq!(amp_amount_to_node(7, 0), 7.0);
+    assert_eq!(node_amount_to_amp(7.0, 0), 7);
+    assert_eq!(node_amount_to_amp(6.6, 0), 7);
+
+    assert_eq!(amp_amount_to_node(12_345, 2), 123.45);
+    assert_eq!(node_amount_to_amp(123.45, 2), 12_345);
+    assert_eq!(node_amount_to_amp(0.1 + 0.2, 2), 30);
+
+    assert_eq!(amp_amount_to_node(1, NODE_AMOUNT_PRECISION), 0.000_000_01);
+    assert_eq!(amp_amount_to_node(2_100_000_000_000_000, 8), 21_000_000.0);
+    assert_eq!(node_amount_to_amp(0.1 + 0.2, 8), 30_000_000);
+    assert_eq!(node_amount_to_amp(21_000_000.0, 8), 2_100_000_000_000_000);
+
+    // Round trips are exact for every precision
+    for precision in [0, 2, 8] {
+        for amount in [0, 1, 99, 123_456_789, 2_100_000_000_000_000] {
+            assert_eq!(
+                node_amount_to_amp(amp_amount_to_node(amount, precision), precision),
+                amount,
+                "precision {precision}, amount {amount}"
+            );
+        }
+    }
+
+    assert_eq!(node_amount_to_amp(-0.5, 8), 0);
+    assert_eq!(node_amount_to_amp(f64::NAN, 8), 0);
+    assert_eq!(node_amount_to_amp(f64::INFINITY, 8), 0);
+}
+
+#[test]
+fn test_received_by_address_multi_asset_amounts() {
+    use amp_rs::model::ReceivedByAddress;
+
+    let asset_id = "5ac9f65c0efcc4775e0baec4ec03abdde22473cd3cf33c0419ca290e0751b225";
+    let json = serde_json::json!({
+        "address": "tlq1qq2xvpcvfup5j8zscjq05u2wxxjcyewk7979f3mmz5l7uw5pqmx6xf5xy50hsn6vhkm5euwt72x878eq6zxx2z58hd7zrsg9qn",
+        "amount": {
+            "bitcoin": 0.5,
+            asset_id: 100.0
+        },
+        "confirmations": 3,
+        "label": "",
+        "txids": [
+            "8f2d0a7a6a2a8e9d1c0b4b1f5e3c7d9a2b4c6d8e0f1a3b5c7d9e1f3a5b7c9d1e"
+        ]
+    });
+
+    let entry: ReceivedByAddress = serde_json::from_value(json).expect("Deserialization failed");
+
+    assert_eq!(entry.amount.as_ref().map(|amounts| amounts.len()), Some(2));
+    assert_eq!(entry.amount_for("bitcoin"), 0.5);
+    assert_eq!(entry.amount_for(asset_id), 100.0);
+    assert_eq!(entry.amount_for("unknown-asset"), 0.0);
+    assert_eq!(entry.confirmations, Some(3));
+    assert_eq!(entry.txids.as_ref().map(Vec::len), Some(1));
+
+    let no_amounts = ReceivedByAddress {
+        address: None,
+        amount: None,
+        confirmations: None,
+        label: None,
+        txids: None,
+    };
+    assert_eq!(no_amounts.amount_for(asset_id), 0.0);
+}
@@ -28,10 +28,8 @@
 
 use amp_rs::signer::LwkSoftwareSigner;
 use amp_rs::{ApiClient, ElementsRpc};
-use dotenvy;
 use serial_test::serial;
 use std::env;
-use tracing_subscriber;
 
 /// Helper function to conditionally print based on nocapture mode
 fn print_if_nocapture(msg: &str) {
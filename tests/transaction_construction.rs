@@ -1,4 +1,6 @@
-use amp_rs::signer::{Signer, SignerError};
+use amp_rs::client::{ConfirmationConfig, RetryConfig};
+use amp_rs::mocks::{DrySigner, MockElementsRpc, DRY_SIGNER_DUMMY_WITNESS};
+use amp_rs::signer::{LwkSoftwareSigner, Signer, SignerError};
 use amp_rs::{AmpError, ElementsRpc, TxInput, Unspent};
 use async_trait::async_trait;
 use httpmock::prelude::*;
@@ -6,6 +8,10 @@ use httpmock::prelude::*;
 use serde_json::json;
 use std::collections::HashMap;
 
+/// Fixed mnemonic for tests that need a signer but never sign with it
+const TEST_MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
 /// Mock signer for testing transaction signing integration
 #[derive(Debug, Clone)]
 struct MockSigner {
@@ -132,18 +138,30 @@ fn create_listunspent_mock(
 ) {
     use httpmock::Method::POST;
 
-    // Specific mock for createrawtransaction - return transaction hex (must come first)
+    // Specific mocks for createrawtransaction and blindrawtransaction - return transaction hex (must come first)
+    for method in ["createrawtransaction", "blindrawtransaction"] {
+        server.mock(|when, then| {
+            when.method(POST).body_contains(method);
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({
+                    "jsonrpc": "1.0",
+                    "id": "amp-client",
+                    "result": "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002",
+                    "error": null
+                }));
+        });
+    }
+
+    // Every change address belongs to the wallet
     server.mock(|when, then| {
-        when.method(POST)
-            .body_contains("createrawtransaction");
-        then.status(200)
-            .header("content-type", "application/json")
-            .json_body(json!({
-                "jsonrpc": "1.0",
-                "id": "amp-client",
-                "result": "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002",
-                "error": null
-            }));
+        when.method(POST).body_contains("getaddressinfo");
+        then.status(200).json_body(json!({
+            "jsonrpc": "1.0",
+            "id": "amp-client",
+            "result": {"ismine": true, "iswatchonly": false},
+            "error": null
+        }));
     });
 
     // Catch-all mock for all other RPC calls (listunspent, loadwallet, etc.)
@@ -272,169 +290,1504 @@ async fn test_utxo_selection_insufficient_funds() {
 }
 
 #[tokio::test]
-async fn test_utxo_selection_no_spendable_utxos() {
+async fn test_utxo_selection_reports_locked_funds() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+    let locked_txid = "ab".repeat(32);
 
-    // Create UTXOs that are not spendable
-    let mut utxos = create_mock_utxos(asset_id, vec![100.0, 50.0]);
-    for utxo in &mut utxos {
-        utxo.spendable = false;
-    }
-    create_listunspent_mock(&server, "test_wallet", asset_id, utxos);
+    let rpc_result = |result: serde_json::Value| json!({"jsonrpc": "1.0", "id": "amp-client", "result": result, "error": null});
+    let utxos = create_mock_utxos(asset_id, vec![10.0]);
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "loadwallet"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!({"name": "test_wallet", "warning": ""})));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listunspent"}"#);
+        then.status(200).json_body(rpc_result(json!(utxos)));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listlockunspent"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!([{"txid": locked_txid, "vout": 1}])));
+    });
+    server.mock(|when, then| {
+        when.method(POST).json_body_partial(format!(
+            r#"{{"method": "gettransaction", "params": ["{locked_txid}", true]}}"#
+        ));
+        then.status(200).json_body(rpc_result(json!({
+            "txid": locked_txid,
+            "confirmations": 12,
+            "hex": "02000000",
+            "details": [
+                {"category": "receive", "vout": 0, "amount": 5.0, "asset": asset_id},
+                {"category": "receive", "vout": 1, "amount": 200.0, "asset": asset_id}
+            ]
+        })));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "getbalances"}"#);
+        then.status(200).json_body(rpc_result(json!({
+            "mine": {"trusted": {}, "untrusted_pending": {}, "immature": {asset_id: 2.5}}
+        })));
+    });
 
     let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
-
-    let result = rpc
-        .select_utxos_for_amount("test_wallet", asset_id, 50.0, 1.0)
-        .await;
-
-    assert!(result.is_err());
-    let error = result.unwrap_err();
-    assert!(error.to_string().contains("No spendable UTXOs"));
+    let error = rpc
+        .select_utxos_for_amount("test_wallet", asset_id, 100.0, 1.0)
+        .await
+        .unwrap_err();
+
+    match &error {
+        AmpError::InsufficientFunds {
+            required,
+            available,
+            locked_amount,
+            immature_amount,
+            ..
+        } => {
+            assert_eq!(*required, 101.0);
+            assert_eq!(*available, 10.0);
+            assert_eq!(*locked_amount, 200.0);
+            assert_eq!(*immature_amount, 2.5);
+        }
+        other => panic!("Expected InsufficientFunds, got {other:?}"),
+    }
+    assert!(error.to_string().contains("200 locked"));
+    assert!(error
+        .retry_instructions()
+        .unwrap()
+        .contains("You have 200 locked"));
 }
 
 #[tokio::test]
-async fn test_utxo_selection_exact_amount_needed() {
+async fn test_utxo_selection_reports_immature_lbtc_under_its_label() {
     let server = MockServer::start();
-    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+    // A regtest policy asset, which getbalances reports under "bitcoin"
+    let policy_asset = "5ac9f65c0efcc4775e0baec4ec03abdde22473cd3cf33c0419ca290e0751b225";
 
-    // Create UTXOs that exactly match the required amount
-    let utxos = create_mock_utxos(asset_id, vec![50.0, 51.0]);
-    create_listunspent_mock(&server, "test_wallet", asset_id, utxos);
+    let utxos = create_mock_utxos(policy_asset, vec![0.1]);
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "loadwallet"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!({"name": "test_wallet", "warning": ""})));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listunspent"}"#);
+        then.status(200).json_body(rpc_result(json!(utxos)));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listlockunspent"}"#);
+        then.status(200).json_body(rpc_result(json!([])));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "getbalances"}"#);
+        then.status(200).json_body(rpc_result(json!({
+            "mine": {"trusted": {"bitcoin": 0.1}, "untrusted_pending": {}, "immature": {"bitcoin": 50.0}}
+        })));
+    });
+    let labels_mock = server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "dumpassetlabels"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!({"bitcoin": policy_asset})));
+    });
 
     let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
-
-    // Need exactly 101.0 (100.0 + 1.0 fee), have 51.0 + 50.0 = 101.0
-    let result = rpc
-        .select_utxos_for_amount("test_wallet", asset_id, 100.0, 1.0)
-        .await;
-
-    assert!(result.is_ok());
-    let (selected_utxos, total_amount) = result.unwrap();
-    assert_eq!(selected_utxos.len(), 2);
-    assert_eq!(total_amount, 101.0);
+    let error = rpc
+        .select_utxos_for_amount("test_wallet", policy_asset, 1.0, 0.0)
+        .await
+        .unwrap_err();
+
+    match &error {
+        AmpError::InsufficientFunds {
+            immature_amount, ..
+        } => assert_eq!(*immature_amount, 50.0),
+        other => panic!("Expected InsufficientFunds, got {other:?}"),
+    }
+    labels_mock.assert();
 }
 
 #[tokio::test]
-async fn test_utxo_selection_algorithm_largest_first() {
+async fn test_lock_unspent_and_list_lock_unspent() {
     let server = MockServer::start();
-    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-
-    // Create UTXOs in random order to test sorting
-    let utxos = create_mock_utxos(asset_id, vec![25.0, 100.0, 10.0, 75.0, 50.0]);
-    create_listunspent_mock(&server, "test_wallet", asset_id, utxos);
+    let first = "cd".repeat(32);
+    let second = "ef".repeat(32);
+    let lock_mock = server.mock(|when, then| {
+        when.method(POST).path("/wallet/test_wallet").json_body_partial(format!(
+            r#"{{"method": "lockunspent", "params": [false, [{{"txid": "{first}", "vout": 2}}, {{"txid": "{second}", "vout": 0}}]]}}"#
+        ));
+        then.status(200)
+            .json_body(json!({"result": true, "error": null, "id": "amp-client"}));
+    });
+    let unlock_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(r#"{"method": "lockunspent", "params": [true]}"#);
+        then.status(200)
+            .json_body(json!({"result": true, "error": null, "id": "amp-client"}));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(r#"{"method": "listlockunspent"}"#);
+        then.status(200).json_body(json!({
+            "result": [{"txid": first, "vout": 2}, {"txid": second, "vout": 0}],
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
 
     let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let outpoints = vec![(first.clone(), 2), (second.clone(), 0)];
+    assert!(rpc.lock_unspent("test_wallet", &outpoints).await.unwrap());
+    lock_mock.assert();
 
-    // Need 120.0 + 1.0 fee = 121.0 total
-    let result = rpc
-        .select_utxos_for_amount("test_wallet", asset_id, 120.0, 1.0)
-        .await;
-
-    assert!(result.is_ok());
-    let (selected_utxos, total_amount) = result.unwrap();
+    let locked = rpc.list_lock_unspent("test_wallet").await.unwrap();
+    let locked: Vec<(String, i64)> = locked.into_iter().map(|o| (o.txid, o.vout)).collect();
+    assert_eq!(locked, vec![(first, 2), (second, 0)]);
 
-    // Should select 100.0 + 75.0 = 175.0 (largest first algorithm)
-    assert!(total_amount >= 121.0);
+    // An empty slice is a no-op; releasing every lock takes unlock_all_unspent
+    assert!(rpc.unlock_unspent("test_wallet", &[]).await.unwrap());
+    assert_eq!(unlock_mock.hits(), 0);
+    assert!(rpc.unlock_all_unspent("test_wallet").await.unwrap());
+    unlock_mock.assert();
 
-    // Verify first UTXO is the largest available
-    assert_eq!(selected_utxos[0].amount, 100.0);
+    // Malformed txids are rejected before reaching the node
+    let result = rpc
+        .lock_unspent("test_wallet", &[("not-a-txid".to_string(), 0)])
+        .await;
+    assert!(matches!(result, Err(AmpError::Validation(_))));
+    lock_mock.assert_hits(1);
+}
 
-    // Verify UTXOs are in descending order by amount
-    for i in 1..selected_utxos.len() {
-        assert!(selected_utxos[i - 1].amount >= selected_utxos[i].amount);
+/// Mocks a wallet holding one asset UTXO and one L-BTC UTXO for a distribution build
+fn mock_distribution_wallet(server: &MockServer, asset_id: &str, lbtc_amount: f64) {
+    let asset_txid = "ab".repeat(32);
+    let lbtc_txid = "bc".repeat(32);
+    let mut utxos = create_mock_utxos(asset_id, vec![150.0]);
+    utxos[0].txid = asset_txid;
+    let mut lbtc = create_mock_utxos(
+        "144c654344aa716d6f3abcc1ca90e5641e4e2a7f633bc09fe3baf64585819a49",
+        vec![lbtc_amount],
+    );
+    lbtc[0].txid = lbtc_txid;
+    utxos.extend(lbtc);
+
+    for method in ["createrawtransaction", "blindrawtransaction"] {
+        server.mock(|when, then| {
+            when.method(POST).body_contains(method);
+            then.status(200).json_body(json!({
+                "result": "0200000000010123456789abcdef00000000000000000002",
+                "error": null,
+                "id": "amp-client"
+            }));
+        });
     }
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listunspent"}"#);
+        then.status(200)
+            .json_body(json!({"result": utxos, "error": null, "id": "amp-client"}));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "loadwallet"}"#);
+        then.status(200).json_body(
+            json!({"result": {"name": "test_wallet"}, "error": null, "id": "amp-client"}),
+        );
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "getaddressinfo"}"#);
+        then.status(200).json_body(json!({
+            "result": {"ismine": true, "iswatchonly": false},
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
 }
 
 #[tokio::test]
-async fn test_transaction_construction_with_mock_signer_success() {
+async fn test_build_distribution_transaction_locks_inputs_until_committed() {
     let server = MockServer::start();
-    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-
-    // Setup UTXO mock
-    let utxos = create_mock_utxos(asset_id, vec![150.0]);
-    create_listunspent_mock(&server, "test_wallet", asset_id, utxos);
-
-    // Setup transaction creation mock
-    let mut address_amounts = HashMap::new();
-    address_amounts.insert("recipient1".to_string(), 100.0);
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    mock_distribution_wallet(&server, asset_id, 0.0005);
+    let lock_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(r#"{"method": "lockunspent", "params": [false]}"#);
+        then.status(200)
+            .json_body(json!({"result": true, "error": null, "id": "amp-client"}));
+    });
+    let unlock_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(r#"{"method": "lockunspent", "params": [true]}"#);
+        then.status(200)
+            .json_body(json!({"result": true, "error": null, "id": "amp-client"}));
+    });
 
-    let mut expected_outputs = HashMap::new();
-    expected_outputs.insert("recipient1".to_string(), 100.0);
-    expected_outputs.insert("address_0".to_string(), 49.0); // Change: 150 - 100 - 1 fee
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let address_amounts = HashMap::from([("recipient1".to_string(), 100.0)]);
 
-    let mut expected_assets = HashMap::new();
-    expected_assets.insert("recipient1".to_string(), asset_id.to_string());
-    expected_assets.insert("address_0".to_string(), asset_id.to_string());
+    let (_, utxos, _, guard) = rpc
+        .build_distribution_transaction_with_lock(
+            "test_wallet",
+            asset_id,
+            address_amounts.clone(),
+            "change",
+            0.0001,
+            true,
+        )
+        .await
+        .unwrap();
 
-    let _expected_inputs = vec![TxInput {
-        txid: "txid_000".to_string(),
-        vout: 0,
-        sequence: None,
-    }];
+    // Asset and fee inputs are locked while the guard is held
+    assert!(lock_mock.hits() >= 1);
+    assert_eq!(utxos.len(), 2);
+    assert_eq!(
+        guard.outpoints(),
+        &[("ab".repeat(32), 0), ("bc".repeat(32), 0)]
+    );
+    assert_eq!(unlock_mock.hits(), 0);
 
-    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    // Dropping without commit spawns the unlock
+    drop(guard);
+    for _ in 0..50 {
+        if unlock_mock.hits() > 0 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    unlock_mock.assert();
 
-    // Test transaction construction
-    let result = rpc
-        .build_distribution_transaction(
+    // A committed guard keeps its locks
+    let (_, _, _, guard) = rpc
+        .build_distribution_transaction_with_lock(
             "test_wallet",
             asset_id,
             address_amounts,
-            "address_0", // change address
-            1.0,         // fee
+            "change",
+            0.0001,
+            true,
         )
-        .await;
+        .await
+        .unwrap();
+    guard.commit();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    unlock_mock.assert_hits(1);
+}
 
-    if result.is_err() {
-        println!("Error: {}", result.as_ref().unwrap_err());
-    }
-    assert!(result.is_ok());
-    let (raw_tx, selected_utxos, change_amount) = result.unwrap();
+#[tokio::test]
+async fn test_build_distribution_from_assignments_resolves_gaid_addresses() {
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    let amp_server = MockServer::start();
+    amp_server.mock(|when, then| {
+        when.method(GET).path("/assets/mock_asset_uuid");
+        then.status(200).json_body(json!({
+            "name": "Mock Asset", "asset_uuid": "mock_asset_uuid", "issuer": 1,
+            "asset_id": asset_id, "reissuance_token_id": null, "requirements": [],
+            "ticker": "MOCK", "precision": 8, "domain": "mock.com", "pubkey": null,
+            "is_registered": true, "is_authorized": true, "is_locked": false,
+            "issuer_authorization_endpoint": null, "transfer_restricted": false
+        }));
+    });
+    amp_server.mock(|when, then| {
+        when.method(GET).path("/registered_users/7");
+        then.status(200).json_body(json!({
+            "id": 7, "GAID": "GA2HsrczzwaFzdJiw5NJM8P4iWKQh1", "is_company": false,
+            "name": "Investor", "categories": [], "creator": 1
+        }));
+    });
+    let gaid_mock = amp_server.mock(|when, then| {
+        when.method(GET)
+            .path("/gaids/GA2HsrczzwaFzdJiw5NJM8P4iWKQh1/address");
+        then.status(200)
+            .json_body(json!({"address": "gaid_address", "error": null}));
+    });
+
+    let rpc_server = MockServer::start();
+    // Registered first so it takes precedence over the wallet's catch-all mock
+    let create_mock = rpc_server.mock(|when, then| {
+        when.method(POST)
+            .body_contains("createrawtransaction")
+            .body_contains(r#""gaid_address":"#)
+            .body_contains(r#""given_address":"#)
+            // Without a node fee estimate the fallback budget of 1000 sats
+            // is the fee and the rest of the L-BTC input is change
+            .body_contains(format!(
+                r#"{{"asset":"{TESTNET_LBTC_ASSET_ID}","change":"0.00049000"}}"#
+            ));
+        then.status(200).json_body(json!({
+            "result": "0200000000010123456789abcdef00000000000000000002",
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    mock_distribution_wallet(&rpc_server, asset_id, 0.0005);
+
+    let client = amp_rs::ApiClient::with_mock_token(
+        url::Url::parse(&amp_server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+    let assignments = [
+        amp_rs::model::AssetDistributionAssignment {
+            user_id: "7".to_string(),
+            address: String::new(),
+            amount: 60.0,
+        },
+        amp_rs::model::AssetDistributionAssignment {
+            user_id: "8".to_string(),
+            address: "given_address".to_string(),
+            amount: 40.0,
+        },
+    ];
+
+    let (raw_tx, utxos) = client
+        .build_distribution_from_assignments(
+            &rpc,
+            "test_wallet",
+            "mock_asset_uuid",
+            &assignments,
+            "change",
+        )
+        .await
+        .unwrap();
 
-    // Verify transaction was built
     assert!(!raw_tx.is_empty());
-    assert_eq!(selected_utxos.len(), 1);
-    assert_eq!(change_amount, 50.0);
+    assert_eq!(utxos.len(), 2);
+    assert_eq!(utxos[0].asset, asset_id);
+    gaid_mock.assert();
+    create_mock.assert();
+
+    for amount in [0.0, f64::NAN, f64::INFINITY] {
+        let invalid = [amp_rs::model::AssetDistributionAssignment {
+            user_id: "8".to_string(),
+            address: "given_address".to_string(),
+            amount,
+        }];
+        let error = client
+            .build_distribution_from_assignments(
+                &rpc,
+                "test_wallet",
+                "mock_asset_uuid",
+                &invalid,
+                "change",
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AmpError::Validation(_)), "{error:?}");
+    }
 }
 
 #[tokio::test]
-async fn test_signer_integration_success() {
-    let server = MockServer::start();
-    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+async fn test_estimate_distribution_fee_prices_estimated_vsize() {
+    let rpc_server = MockServer::start();
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "estimatesmartfee"}"#);
+        // 2 sat/vB
+        then.status(200).json_body(json!({
+            "result": {"feerate": 0.000_02, "blocks": 2},
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "getmempoolinfo"}"#);
+        then.status(200).json_body(json!({
+            "result": {
+                "size": 0,
+                "bytes": 0,
+                "usage": 0,
+                "mempoolminfee": 0.000_001,
+                "minrelaytxfee": 0.000_001
+            },
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
 
-    let unsigned_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002";
-    let expected_signed_tx = format!("{}deadbeefcafebabe1234567890abcdef", unsigned_tx);
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
 
-    let mock_signer = MockSigner::new_success();
+    // Asset and L-BTC inputs, three recipients plus change and fee outputs:
+    // 2 * 70 + 5 * 1300 = 6640 vB at 2 sat/vB
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    let fee = rpc.estimate_distribution_fee(asset_id, 3).await.unwrap();
+    assert_eq!(fee, 13_280);
 
-    let result = rpc.sign_transaction(unsigned_tx, &mock_signer).await;
+    // Distributing L-BTC itself needs no separate fee input
+    let lbtc = "144c654344aa716d6f3abcc1ca90e5641e4e2a7f633bc09fe3baf64585819a49";
+    let fee = rpc.estimate_distribution_fee(lbtc, 3).await.unwrap();
+    assert_eq!(fee, 13_140);
 
-    assert!(result.is_ok());
-    let signed_tx = result.unwrap();
-    assert_eq!(signed_tx, expected_signed_tx);
-    assert_eq!(mock_signer.call_count(), 1);
+    let err = rpc
+        .estimate_distribution_fee(asset_id, 0)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("at least one recipient"));
 }
 
 #[tokio::test]
-async fn test_signer_integration_failure() {
-    let server = MockServer::start();
-    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+async fn test_get_issuance_transaction_skips_reissuances() {
+    let asset_id = "3".repeat(64);
+    let issuance_txid = "4".repeat(64);
+    let rpc_server = MockServer::start();
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listissuances"}"#);
+        then.status(200).json_body(json!({
+            "result": [
+                {
+                    "txid": "9".repeat(64),
+                    "vin": 0,
+                    "asset": asset_id,
+                    "assetamount": 500.0,
+                    "isreissuance": true
+                },
+                {
+                    "txid": issuance_txid,
+                    "vin": 0,
+                    "entropy": "5".repeat(64),
+                    "asset": asset_id,
+                    "assetamount": 1000.0,
+                    "token": "6".repeat(64),
+                    "tokenamount": 1.0,
+                    "isreissuance": false
+                }
+            ],
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    let tx_mock = rpc_server.mock(|when, then| {
+        when.method(POST).json_body_partial(
+            json!({"method": "gettransaction", "params": [issuance_txid, true]}).to_string(),
+        );
+        then.status(200).json_body(json!({
+            "result": {
+                "txid": issuance_txid,
+                "confirmations": 120,
+                "blockheight": 1000,
+                "hex": "020000000101",
+                "blockhash": "7".repeat(64),
+                "details": []
+            },
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
 
-    let unsigned_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002";
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
 
-    let mock_signer = MockSigner::new_failure();
+    let issuance = rpc.get_issuance_transaction(&asset_id).await.unwrap();
 
-    let result = rpc.sign_transaction(unsigned_tx, &mock_signer).await;
+    tx_mock.assert();
+    assert_eq!(issuance.txid, issuance_txid);
+    assert_eq!(issuance.confirmations, 120);
+    assert_eq!(issuance.blockheight, Some(1000));
 
-    assert!(result.is_err());
-    let error = result.unwrap_err();
-    assert!(error.to_string().contains("Mock signing failure"));
-    assert_eq!(mock_signer.call_count(), 1);
+    let unknown = rpc.get_issuance_transaction(&"8".repeat(64)).await;
+    assert!(matches!(unknown, Err(AmpError::Validation(_))));
 }
 
-#[tokio::test]
+const DISTRIBUTION_ASSET_UUID: &str = "550e8400-e29b-41d4-a716-446655440000";
+const DISTRIBUTION_ASSET_ID: &str =
+    "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+
+/// Wraps `result` in a successful JSON-RPC response
+fn rpc_result(result: serde_json::Value) -> serde_json::Value {
+    json!({"result": result, "error": null, "id": "amp-client"})
+}
+
+/// Mocks the AMP asset and its (empty) treasury addresses, read by
+/// `distribute_asset` before it creates a distribution
+fn mock_distribution_asset(server: &MockServer) {
+    server.mock(|when, then| {
+        when.method(GET).path(format!(
+            "/assets/{DISTRIBUTION_ASSET_UUID}/treasury-addresses"
+        ));
+        then.status(200).json_body(json!([]));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/assets/{DISTRIBUTION_ASSET_UUID}"));
+        then.status(200).json_body(json!({
+            "name": "Mock Asset", "asset_uuid": DISTRIBUTION_ASSET_UUID, "issuer": 1,
+            "asset_id": DISTRIBUTION_ASSET_ID, "reissuance_token_id": null, "requirements": [],
+            "ticker": "MOCK", "precision": 8, "domain": "mock.com", "pubkey": null,
+            "is_registered": true, "is_authorized": true, "is_locked": false,
+            "issuer_authorization_endpoint": null, "transfer_restricted": false
+        }));
+    });
+}
+
+/// Mocks the `getnetworkinfo` and `getblockchaininfo` calls orchestrators
+/// make to check the node is up
+fn mock_node_status(server: &MockServer) {
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "getnetworkinfo"}"#);
+        then.status(200).json_body(rpc_result(json!({
+            "version": 230000, "subversion": "/Elements Core:23.0.0/",
+            "protocolversion": 70016, "localservices": "0000000000000409",
+            "localrelay": true, "timeoffset": 0, "networkactive": true,
+            "connections": 8, "networks": [], "relayfee": 0.00001,
+            "incrementalfee": 0.00001, "localaddresses": [], "warnings": ""
+        })));
+    });
+    mock_blockchain_chain(server, "liquidtestnet");
+}
+
+/// Mocks the node calls `distribute_asset` makes around `sendmany`: node
+/// status, wallet loading, confirmations, change and receive history
+///
+/// Tests register their own mocks for these methods first to override them.
+fn mock_distribution_node(server: &MockServer) {
+    mock_node_status(server);
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "loadwallet"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!({"name": "test_wallet", "warning": ""})));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "gettransaction"}"#);
+        then.status(200).json_body(rpc_result(json!({
+            "txid": "0".repeat(64), "confirmations": 2, "blockheight": 101,
+            "hex": "00", "details": []
+        })));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listunspent"}"#);
+        then.status(200).json_body(rpc_result(json!([])));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listreceivedbyaddress"}"#);
+        then.status(200).json_body(rpc_result(json!([])));
+    });
+}
+
+#[tokio::test]
+async fn test_distribute_asset_splits_recipients_into_batches() {
+    let asset_uuid = DISTRIBUTION_ASSET_UUID;
+    let asset_id = DISTRIBUTION_ASSET_ID;
+    let amp_server = MockServer::start();
+    mock_distribution_asset(&amp_server);
+    // Each batch starts with a different user, so the first user in the
+    // request body identifies the batch
+    let create_mocks: Vec<_> = [0, 100, 200]
+        .iter()
+        .enumerate()
+        .map(|(batch, first_user)| {
+            amp_server.mock(|when, then| {
+                when.method(GET)
+                    .path(format!("/assets/{asset_uuid}/distributions/create"))
+                    .body_contains(format!(r#""user_uuid":"user_{first_user}""#));
+                then.status(200).json_body(json!({
+                    "distribution_uuid": format!("dist-{batch}"),
+                    "map_address_amount": {format!("batch_{batch}_address"): 1.0},
+                    "map_address_asset": {format!("batch_{batch}_address"): asset_id},
+                    "asset_id": asset_id
+                }));
+            })
+        })
+        .collect();
+    let confirm_mock = amp_server.mock(|when, then| {
+        when.method(POST)
+            .path_contains("/distributions/dist-")
+            .path_contains("/confirm");
+        then.status(200).json_body(json!({}));
+    });
+
+    let rpc_server = MockServer::start();
+    let sendmany_mocks: Vec<_> = (0..3)
+        .map(|batch| {
+            rpc_server.mock(|when, then| {
+                when.method(POST)
+                    .json_body_partial(r#"{"method": "sendmany"}"#)
+                    .body_contains(format!("batch_{batch}_address"));
+                then.status(200)
+                    .json_body(rpc_result(json!(format!("{batch}").repeat(64))));
+            })
+        })
+        .collect();
+    mock_distribution_node(&rpc_server);
+
+    let client = amp_rs::ApiClient::with_mock_token(
+        url::Url::parse(&amp_server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap()
+    .with_max_outputs_per_tx(Some(100));
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+    let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+    let assignments: Vec<_> = (0..250)
+        .map(|i| amp_rs::model::AssetDistributionAssignment {
+            user_id: format!("user_{i}"),
+            address: format!("lq1qaddress{i}"),
+            amount: 1.0,
+        })
+        .collect();
+
+    let txids = client
+        .distribute_asset(asset_uuid, assignments, &rpc, "test_wallet", &signer)
+        .await
+        .unwrap();
+
+    assert_eq!(txids, vec!["0".repeat(64), "1".repeat(64), "2".repeat(64)]);
+    for (create_mock, sendmany_mock) in create_mocks.iter().zip(&sendmany_mocks) {
+        create_mock.assert_hits(1);
+        sendmany_mock.assert_hits(1);
+    }
+    confirm_mock.assert_hits(3);
+}
+
+#[tokio::test]
+async fn test_distribute_asset_creates_and_confirms_distribution_once() {
+    let address = "lq1qrecipient";
+    let txid = "a".repeat(64);
+    let amp_server = MockServer::start();
+    mock_distribution_asset(&amp_server);
+    let create_mock = amp_server.mock(|when, then| {
+        when.method(GET)
+            .path(format!(
+                "/assets/{DISTRIBUTION_ASSET_UUID}/distributions/create"
+            ))
+            .body_contains(format!(r#""address":"{address}""#));
+        then.status(200).json_body(json!({
+            "distribution_uuid": "dist-0",
+            "map_address_amount": {address: 1.0},
+            "map_address_asset": {address: DISTRIBUTION_ASSET_ID},
+            "asset_id": DISTRIBUTION_ASSET_ID
+        }));
+    });
+    let confirm_mock = amp_server.mock(|when, then| {
+        when.method(POST)
+            .path(format!(
+                "/assets/{DISTRIBUTION_ASSET_UUID}/distributions/dist-0/confirm"
+            ))
+            .body_contains(&txid);
+        then.status(200).json_body(json!({}));
+    });
+
+    let rpc_server = MockServer::start();
+    let sendmany_mock = rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "sendmany"}"#)
+            .body_contains(address);
+        then.status(200).json_body(rpc_result(json!(txid)));
+    });
+    mock_distribution_node(&rpc_server);
+
+    let client = amp_rs::ApiClient::with_mock_token(
+        url::Url::parse(&amp_server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+    let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+    let assignments = vec![amp_rs::model::AssetDistributionAssignment {
+        user_id: "user_1".to_string(),
+        address: address.to_string(),
+        amount: 1.0,
+    }];
+
+    let txids = client
+        .distribute_asset(
+            DISTRIBUTION_ASSET_UUID,
+            assignments,
+            &rpc,
+            "test_wallet",
+            &signer,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(txids, vec![txid]);
+    create_mock.assert_hits(1);
+    sendmany_mock.assert_hits(1);
+    confirm_mock.assert_hits(1);
+}
+
+#[tokio::test]
+async fn test_distribute_asset_manual_raw_tx_returns_lbtc_change() {
+    let address = "lq1qrecipient";
+    let txid = "c".repeat(64);
+    let amp_server = MockServer::start();
+    mock_distribution_asset(&amp_server);
+    amp_server.mock(|when, then| {
+        when.method(GET).path(format!(
+            "/assets/{DISTRIBUTION_ASSET_UUID}/distributions/create"
+        ));
+        then.status(200).json_body(json!({
+            "distribution_uuid": "dist-0",
+            "map_address_amount": {address: 1.0},
+            "map_address_asset": {address: DISTRIBUTION_ASSET_ID},
+            "asset_id": DISTRIBUTION_ASSET_ID
+        }));
+    });
+    let confirm_mock = amp_server.mock(|when, then| {
+        when.method(POST)
+            .path(format!(
+                "/assets/{DISTRIBUTION_ASSET_UUID}/distributions/dist-0/confirm"
+            ))
+            .body_contains(&txid);
+        then.status(200).json_body(json!({}));
+    });
+
+    let rpc_server = MockServer::start();
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "estimatesmartfee"}"#);
+        // 2 sat/vB
+        then.status(200)
+            .json_body(rpc_result(json!({"feerate": 0.000_02, "blocks": 2})));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "getnewaddress"}"#);
+        then.status(200).json_body(rpc_result(json!("change")));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "lockunspent"}"#);
+        then.status(200).json_body(rpc_result(json!(true)));
+    });
+    // One recipient: 2 * 70 + 3 * 1300 = 4040 vB at 2 sat/vB is 8080 sats
+    // of fee, and the rest of the 0.001 L-BTC input comes back as change
+    let create_mock = rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "createrawtransaction"}"#)
+            .body_contains(format!(
+                r#"{{"asset":"{TESTNET_LBTC_ASSET_ID}","change":"0.00091920"}}"#
+            ));
+        then.status(200).json_body(rpc_result(json!("00")));
+    });
+    let broadcast_mock = rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "sendrawtransaction"}"#);
+        then.status(200).json_body(rpc_result(json!(txid)));
+    });
+    mock_distribution_wallet(&rpc_server, DISTRIBUTION_ASSET_ID, 0.001);
+    mock_distribution_node(&rpc_server);
+
+    let client = amp_rs::ApiClient::with_mock_token(
+        url::Url::parse(&amp_server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap()
+    .with_distribution_method(amp_rs::client::DistributionMethod::ManualRawTx);
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+    let signer =
+        DrySigner::with_signed_hex("0200000000010123456789abcdef00000000000000000002deadbeef");
+    let assignments = vec![amp_rs::model::AssetDistributionAssignment {
+        user_id: "user_1".to_string(),
+        address: address.to_string(),
+        amount: 1.0,
+    }];
+
+    let txids = client
+        .distribute_asset(
+            DISTRIBUTION_ASSET_UUID,
+            assignments,
+            &rpc,
+            "test_wallet",
+            &signer,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(txids, vec![txid]);
+    create_mock.assert_hits(1);
+    broadcast_mock.assert_hits(1);
+    confirm_mock.assert_hits(1);
+}
+
+#[tokio::test]
+async fn test_distribute_asset_rejects_reused_address_before_creating_distribution() {
+    let amp_server = MockServer::start();
+    mock_distribution_asset(&amp_server);
+    let create_mock = amp_server.mock(|when, then| {
+        when.path_contains("/distributions/create");
+        then.status(200).json_body(json!({}));
+    });
+
+    let rpc_server = MockServer::start();
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listreceivedbyaddress"}"#);
+        then.status(200).json_body(rpc_result(json!([{
+            "address": "lq1qreused",
+            "amount": {DISTRIBUTION_ASSET_ID: 5.0},
+            "confirmations": 12,
+            "label": "",
+            "txids": ["1".repeat(64)]
+        }])));
+    });
+    mock_distribution_node(&rpc_server);
+
+    let client = amp_rs::ApiClient::with_mock_token(
+        url::Url::parse(&amp_server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap()
+    .with_strict_address_reuse(true);
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+    let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+    let assignments = vec![amp_rs::model::AssetDistributionAssignment {
+        user_id: "user_1".to_string(),
+        address: "lq1qreused".to_string(),
+        amount: 1.0,
+    }];
+
+    let error = client
+        .distribute_asset(
+            DISTRIBUTION_ASSET_UUID,
+            assignments,
+            &rpc,
+            "test_wallet",
+            &signer,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AmpError::Validation(_)), "{error:?}");
+    assert!(error.to_string().contains("lq1qreused"), "{error}");
+    create_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn test_distribute_asset_rejects_unconfidential_recipient_before_creating_distribution() {
+    let unconfidential = "tex1q50rtrmj2f8vl9tem8qpfw36ylw5jg9j24kmnvl";
+    let amp_server = MockServer::start();
+    mock_distribution_asset(&amp_server);
+    let create_mock = amp_server.mock(|when, then| {
+        when.path_contains("/distributions/create");
+        then.status(200).json_body(json!({
+            "distribution_uuid": "dist-0",
+            "map_address_amount": {unconfidential: 1.0},
+            "map_address_asset": {unconfidential: DISTRIBUTION_ASSET_ID},
+            "asset_id": DISTRIBUTION_ASSET_ID
+        }));
+    });
+
+    let rpc_server = MockServer::start();
+    let sendmany_mock = rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "sendmany"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!("0".repeat(64))));
+    });
+    mock_distribution_node(&rpc_server);
+
+    let client = amp_rs::ApiClient::with_mock_token(
+        url::Url::parse(&amp_server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap();
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+    let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+    let assignments = vec![amp_rs::model::AssetDistributionAssignment {
+        user_id: "user_1".to_string(),
+        address: unconfidential.to_string(),
+        amount: 1.0,
+    }];
+
+    let error = client
+        .distribute_asset(
+            DISTRIBUTION_ASSET_UUID,
+            assignments,
+            &rpc,
+            "test_wallet",
+            &signer,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AmpError::Validation(_)), "{error:?}");
+    assert!(error.to_string().contains(unconfidential), "{error}");
+    create_mock.assert_hits(0);
+    sendmany_mock.assert_hits(0);
+}
+
+/// Advances the paused clock by 100ms, then sleeps for real so node responses
+/// arrive before the client's request timeouts elapse
+async fn step_paused_clock() {
+    tokio::time::advance(std::time::Duration::from_millis(100)).await;
+    std::thread::sleep(std::time::Duration::from_millis(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_burn_asset_waits_for_burn_confirmations() {
+    let asset_uuid = DISTRIBUTION_ASSET_UUID;
+    let asset_id = DISTRIBUTION_ASSET_ID;
+    let burn_txid = "7ceabde8d7c1596b8b4af27286681dbde9c1551614b9788b6f84b9a3789d3184";
+    let amp_server = MockServer::start();
+    amp_server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/assets/{asset_uuid}/burn-request"));
+        then.status(200).json_body(json!({
+            "command": "destroyamount", "min_supported_client_script_version": 1,
+            "base_url": "http://localhost:8080", "asset_uuid": asset_uuid,
+            "asset_id": asset_id, "amount": 1.0,
+            "utxos": [{"txid": "ab".repeat(32), "vout": 0}]
+        }));
+    });
+    amp_server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/assets/{asset_uuid}/balance"));
+        then.status(200).json_body(json!({"lost_outputs": []}));
+    });
+    let confirm_mock = amp_server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/assets/{asset_uuid}/burn-confirm"));
+        then.status(200).body("");
+    });
+
+    let rpc_server = MockServer::start();
+    // Enough for a distribution, one short of the burn threshold
+    let mut pending_mock = rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "gettransaction"}"#);
+        then.status(200).json_body(rpc_result(json!({
+            "txid": burn_txid, "confirmations": 3, "hex": "00", "details": []
+        })));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "listunspent"}"#);
+        then.status(200).json_body(rpc_result(json!([{
+            "txid": "ab".repeat(32), "vout": 0, "amount": 1.0, "asset": asset_id,
+            "address": "mock_address", "spendable": true
+        }])));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "getbalance"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!({asset_id: 1.0})));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "destroyamount"}"#);
+        then.status(200).json_body(rpc_result(json!(burn_txid)));
+    });
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "loadwallet"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!({"name": "test_wallet", "warning": ""})));
+    });
+    mock_node_status(&rpc_server);
+
+    let client = amp_rs::ApiClient::with_mock_token(
+        url::Url::parse(&amp_server.base_url()).unwrap(),
+        "mock_token".to_string(),
+    )
+    .unwrap()
+    .with_confirmation_policy(amp_rs::ConfirmationPolicy {
+        burn: 4,
+        poll_interval_secs: 1,
+        ..amp_rs::ConfirmationPolicy::default()
+    });
+    assert!(client.confirmation_policy().distribution < 3);
+    let rpc = ElementsRpc::new(rpc_server.url("/"), "user".to_string(), "pass".to_string());
+    let burn = tokio::spawn(async move {
+        client
+            .burn_asset(
+                asset_uuid,
+                100_000_000,
+                &rpc,
+                "test_wallet",
+                &DrySigner::new(),
+            )
+            .await
+    });
+
+    // Three confirmations are polled twice without confirming the burn
+    while pending_mock.hits() < 2 {
+        if burn.is_finished() {
+            panic!("burn finished early: {:?}", burn.await);
+        }
+        step_paused_clock().await;
+    }
+    confirm_mock.assert_hits(0);
+
+    pending_mock.delete();
+    rpc_server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "gettransaction"}"#);
+        then.status(200).json_body(rpc_result(json!({
+            "txid": burn_txid, "confirmations": 4, "hex": "00", "details": []
+        })));
+    });
+    while !burn.is_finished() {
+        step_paused_clock().await;
+    }
+
+    burn.await.unwrap().unwrap();
+    confirm_mock.assert_hits(1);
+}
+
+#[tokio::test]
+async fn test_build_distribution_transaction_attaches_diagnostics_on_blind_failure() {
+    let server = MockServer::start();
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    // Registered first so it takes precedence over the wallet's successful blind mock
+    server.mock(|when, then| {
+        when.method(POST).body_contains("blindrawtransaction");
+        then.status(200).json_body(json!({
+            "result": null,
+            "error": {"code": -8, "message": "Unable to blind transaction"},
+            "id": "amp-client"
+        }));
+    });
+    mock_distribution_wallet(&server, asset_id, 0.0005);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let address_amounts = HashMap::from([("recipient1".to_string(), 100.0)]);
+
+    let error = rpc
+        .build_distribution_transaction("test_wallet", asset_id, address_amounts, "change", 0.0001)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.kind(), "distribution_failed");
+    assert!(error.to_string().contains("Unable to blind transaction"));
+    let diagnostics = error.distribution_diagnostics().unwrap();
+    assert_eq!(diagnostics.wallet_name, "test_wallet");
+    let inputs: Vec<(String, u32)> = diagnostics
+        .inputs
+        .iter()
+        .map(|input| (input.txid.clone(), input.vout))
+        .collect();
+    assert_eq!(inputs, vec![("ab".repeat(32), 0), ("bc".repeat(32), 0)]);
+    assert_eq!(
+        diagnostics.outputs,
+        vec![
+            ("recipient1".to_string(), 100.0, asset_id.to_string()),
+            ("change".to_string(), 50.0, asset_id.to_string()),
+            (
+                "change".to_string(),
+                0.0004,
+                TESTNET_LBTC_ASSET_ID.to_string()
+            ),
+        ]
+    );
+    assert_eq!(
+        diagnostics.raw_transaction.as_deref(),
+        Some("0200000000010123456789abcdef00000000000000000002")
+    );
+}
+
+#[tokio::test]
+async fn test_build_distribution_transaction_without_lock_leaves_inputs_unlocked() {
+    let server = MockServer::start();
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    mock_distribution_wallet(&server, asset_id, 0.0005);
+    let lock_mock = server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "lockunspent"}"#);
+        then.status(200)
+            .json_body(json!({"result": true, "error": null, "id": "amp-client"}));
+    });
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let (_, _, _, guard) = rpc
+        .build_distribution_transaction_with_lock(
+            "test_wallet",
+            asset_id,
+            HashMap::from([("recipient1".to_string(), 100.0)]),
+            "change",
+            0.0001,
+            false,
+        )
+        .await
+        .unwrap();
+
+    assert!(guard.outpoints().is_empty());
+    drop(guard);
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    lock_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn test_build_distribution_transaction_rejects_fee_above_ceiling() {
+    let server = MockServer::start();
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    mock_distribution_wallet(&server, asset_id, 0.01);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    assert_eq!(rpc.max_fee_sats(), amp_rs::client::DEFAULT_MAX_FEE_SATS);
+    let address_amounts = HashMap::from([("recipient1".to_string(), 100.0)]);
+
+    let err = rpc
+        .build_distribution_transaction(
+            "test_wallet",
+            asset_id,
+            address_amounts.clone(),
+            "change",
+            0.002,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AmpError::Validation(_)));
+    assert!(err.to_string().contains("200000 sats"));
+
+    // Raising the ceiling lets the same transaction through
+    let rpc = rpc.with_max_fee_sats(200_000);
+    assert!(rpc
+        .build_distribution_transaction("test_wallet", asset_id, address_amounts, "change", 0.002)
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_build_distribution_transaction_returns_lbtc_change() {
+    let server = MockServer::start();
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    // Registered first so it wins over the catch-all in mock_distribution_wallet
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "createrawtransaction"}"#)
+            .body_contains(format!(
+                r#"{{"asset":"{TESTNET_LBTC_ASSET_ID}","change":"0.00900000"}}"#
+            ));
+        then.status(200)
+            .json_body(json!({"result": "00", "error": null, "id": "amp-client"}));
+    });
+    mock_distribution_wallet(&server, asset_id, 0.01);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    rpc.build_distribution_transaction(
+        "test_wallet",
+        asset_id,
+        HashMap::from([("recipient1".to_string(), 100.0)]),
+        "change",
+        0.001,
+    )
+    .await
+    .unwrap();
+
+    create_mock.assert_hits(1);
+}
+
+#[tokio::test]
+async fn test_build_distribution_transaction_imports_blech32_confidential_recipient() {
+    let server = MockServer::start();
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    let confidential = "tlq1qqfttx29npj9ltqu7yszcw3u8jsytmvmzg8wfctnuvx065y4jjgyk0g7xk8hy5jwe72hnkwqzjar5f7afysty56vff0m9nk7h5";
+    mock_distribution_wallet(&server, asset_id, 0.0005);
+    mock_blockchain_chain(&server, "liquidtestnet");
+    let import_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(format!(
+                r#"{{"method": "importaddress", "params": ["{confidential}"]}}"#
+            ));
+        then.status(200)
+            .json_body(json!({"result": null, "error": null, "id": "amp-client"}));
+    });
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string())
+        .with_allow_unconfidential(true);
+    let address_amounts = HashMap::from([
+        (confidential.to_string(), 60.0),
+        (
+            "tex1q50rtrmj2f8vl9tem8qpfw36ylw5jg9j24kmnvl".to_string(),
+            40.0,
+        ),
+    ]);
+    rpc.build_distribution_transaction("test_wallet", asset_id, address_amounts, "change", 0.0001)
+        .await
+        .unwrap();
+
+    // Only the confidential recipient is imported, though it does not start with 'v'
+    import_mock.assert_hits(1);
+}
+
+#[tokio::test]
+async fn test_build_distribution_transaction_rejects_foreign_change_address() {
+    let server = MockServer::start();
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    // Registered before the wallet's mocks, which report every address as owned
+    let foreign_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(r#"{"method": "getaddressinfo", "params": ["foreign_change"]}"#);
+        then.status(200).json_body(json!({
+            "result": {"ismine": false, "iswatchonly": false},
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    // A watch-only wallet spends its change with an external signer
+    let watch_only_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(r#"{"method": "getaddressinfo", "params": ["watch_only_change"]}"#);
+        then.status(200).json_body(json!({
+            "result": {"ismine": false, "iswatchonly": true},
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    mock_distribution_wallet(&server, asset_id, 0.0005);
+    let lock_mock = server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "lockunspent"}"#);
+        then.status(200)
+            .json_body(json!({"result": true, "error": null, "id": "amp-client"}));
+    });
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let address_amounts = HashMap::from([("recipient1".to_string(), 100.0)]);
+
+    for change in ["owned_change", "watch_only_change"] {
+        rpc.build_distribution_transaction(
+            "test_wallet",
+            asset_id,
+            address_amounts.clone(),
+            change,
+            0.0001,
+        )
+        .await
+        .unwrap();
+    }
+    watch_only_mock.assert();
+
+    let err = rpc
+        .build_distribution_transaction_with_lock(
+            "test_wallet",
+            asset_id,
+            address_amounts,
+            "foreign_change",
+            0.0001,
+            true,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AmpError::Validation(_)), "{err:?}");
+    assert!(
+        err.to_string()
+            .contains("change address not controlled by wallet"),
+        "{err}"
+    );
+    foreign_mock.assert();
+    // Rejected before any input is selected or locked
+    assert_eq!(lock_mock.hits(), 0);
+}
+
+#[tokio::test]
+async fn test_build_distribution_transaction_rejects_unconfidential_recipient() {
+    let server = MockServer::start();
+    let asset_id = "02f22f8d9c76ab41661a2729e4752e2c5d1a263012141b86ea98af5472df5189";
+    let unconfidential = "tex1q50rtrmj2f8vl9tem8qpfw36ylw5jg9j24kmnvl";
+    mock_distribution_wallet(&server, asset_id, 0.0005);
+    mock_blockchain_chain(&server, "liquidtestnet");
+    let address_amounts = HashMap::from([(unconfidential.to_string(), 40.0)]);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    assert!(!rpc.allow_unconfidential());
+    let err = rpc
+        .build_distribution_transaction(
+            "test_wallet",
+            asset_id,
+            address_amounts.clone(),
+            "change",
+            0.0001,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AmpError::Validation(_)), "{err:?}");
+    assert!(err.to_string().contains(unconfidential), "{err}");
+
+    let rpc = rpc.with_allow_unconfidential(true);
+    rpc.build_distribution_transaction("test_wallet", asset_id, address_amounts, "change", 0.0001)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_utxo_selection_no_spendable_utxos() {
+    let server = MockServer::start();
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+
+    // Create UTXOs that are not spendable
+    let mut utxos = create_mock_utxos(asset_id, vec![100.0, 50.0]);
+    for utxo in &mut utxos {
+        utxo.spendable = false;
+    }
+    create_listunspent_mock(&server, "test_wallet", asset_id, utxos);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    let result = rpc
+        .select_utxos_for_amount("test_wallet", asset_id, 50.0, 1.0)
+        .await;
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert!(error.to_string().contains("No spendable UTXOs"));
+}
+
+#[tokio::test]
+async fn test_utxo_selection_exact_amount_needed() {
+    let server = MockServer::start();
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+
+    // Create UTXOs that exactly match the required amount
+    let utxos = create_mock_utxos(asset_id, vec![50.0, 51.0]);
+    create_listunspent_mock(&server, "test_wallet", asset_id, utxos);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    // Need exactly 101.0 (100.0 + 1.0 fee), have 51.0 + 50.0 = 101.0
+    let result = rpc
+        .select_utxos_for_amount("test_wallet", asset_id, 100.0, 1.0)
+        .await;
+
+    assert!(result.is_ok());
+    let (selected_utxos, total_amount) = result.unwrap();
+    assert_eq!(selected_utxos.len(), 2);
+    assert_eq!(total_amount, 101.0);
+}
+
+#[tokio::test]
+async fn test_utxo_selection_algorithm_largest_first() {
+    let server = MockServer::start();
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+
+    // Create UTXOs in random order to test sorting
+    let utxos = create_mock_utxos(asset_id, vec![25.0, 100.0, 10.0, 75.0, 50.0]);
+    create_listunspent_mock(&server, "test_wallet", asset_id, utxos);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    // Need 120.0 + 1.0 fee = 121.0 total
+    let result = rpc
+        .select_utxos_for_amount("test_wallet", asset_id, 120.0, 1.0)
+        .await;
+
+    assert!(result.is_ok());
+    let (selected_utxos, total_amount) = result.unwrap();
+
+    // Should select 100.0 + 75.0 = 175.0 (largest first algorithm)
+    assert!(total_amount >= 121.0);
+
+    // Verify first UTXO is the largest available
+    assert_eq!(selected_utxos[0].amount, 100.0);
+
+    // Verify UTXOs are in descending order by amount
+    for i in 1..selected_utxos.len() {
+        assert!(selected_utxos[i - 1].amount >= selected_utxos[i].amount);
+    }
+}
+
+#[tokio::test]
+async fn test_transaction_construction_with_mock_signer_success() {
+    let server = MockServer::start();
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+
+    // Setup UTXO mock
+    let utxos = create_mock_utxos(asset_id, vec![150.0]);
+    create_listunspent_mock(&server, "test_wallet", asset_id, utxos);
+
+    // Setup transaction creation mock
+    let mut address_amounts = HashMap::new();
+    address_amounts.insert("recipient1".to_string(), 100.0);
+
+    let mut expected_outputs = HashMap::new();
+    expected_outputs.insert("recipient1".to_string(), 100.0);
+    expected_outputs.insert("address_0".to_string(), 49.0); // Change: 150 - 100 - 1 fee
+
+    let mut expected_assets = HashMap::new();
+    expected_assets.insert("recipient1".to_string(), asset_id.to_string());
+    expected_assets.insert("address_0".to_string(), asset_id.to_string());
+
+    let _expected_inputs = [TxInput {
+        txid: "txid_000".to_string(),
+        vout: 0,
+        sequence: None,
+    }];
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    // Test transaction construction
+    let result = rpc
+        .build_distribution_transaction(
+            "test_wallet",
+            asset_id,
+            address_amounts,
+            "address_0", // change address
+            1.0,         // fee
+        )
+        .await;
+
+    if let Err(e) = &result {
+        println!("Error: {}", e);
+    }
+    assert!(result.is_ok());
+    let (raw_tx, selected_utxos, change_amount) = result.unwrap();
+
+    // Verify transaction was built
+    assert!(!raw_tx.is_empty());
+    assert_eq!(selected_utxos.len(), 1);
+    assert_eq!(change_amount, 50.0);
+}
+
+#[tokio::test]
+async fn test_signer_integration_success() {
+    let server = MockServer::start();
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    let unsigned_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002";
+    let expected_signed_tx = format!("{}deadbeefcafebabe1234567890abcdef", unsigned_tx);
+
+    let mock_signer = MockSigner::new_success();
+
+    let result = rpc.sign_transaction(unsigned_tx, &mock_signer).await;
+
+    assert!(result.is_ok());
+    let signed_tx = result.unwrap();
+    assert_eq!(signed_tx, expected_signed_tx);
+    assert_eq!(mock_signer.call_count(), 1);
+}
+
+#[tokio::test]
+async fn test_signer_integration_failure() {
+    let server = MockServer::start();
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    let unsigned_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002";
+
+    let mock_signer = MockSigner::new_failure();
+
+    let result = rpc.sign_transaction(unsigned_tx, &mock_signer).await;
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert!(error.to_string().contains("Mock signing failure"));
+    assert_eq!(mock_signer.call_count(), 1);
+}
+
+#[tokio::test]
 async fn test_signer_integration_with_expected_input() {
     let server = MockServer::start();
     let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
@@ -450,42 +1803,339 @@ async fn test_signer_integration_with_expected_input() {
 }
 
 #[tokio::test]
-async fn test_signer_integration_with_wrong_input() {
+async fn test_signer_integration_with_wrong_input() {
+    let server = MockServer::start();
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    let unsigned_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002";
+    let expected_tx = "different_transaction_hex";
+
+    let mock_signer = MockSigner::with_expected_input(expected_tx.to_string());
+
+    let result = rpc.sign_transaction(unsigned_tx, &mock_signer).await;
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert!(error.to_string().contains("Expected transaction"));
+    assert_eq!(mock_signer.call_count(), 1);
+}
+
+#[tokio::test]
+async fn test_signer_integration_with_custom_return_value() {
+    let server = MockServer::start();
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    let unsigned_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002";
+    let custom_signed_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000deadbeef00000000";
+
+    let mock_signer = MockSigner::with_return_value(custom_signed_tx.to_string());
+
+    let result = rpc.sign_transaction(unsigned_tx, &mock_signer).await;
+
+    if let Err(e) = &result {
+        println!("Error: {}", e);
+    }
+    assert!(result.is_ok());
+    let signed_tx = result.unwrap();
+    assert_eq!(signed_tx, custom_signed_tx);
+    assert_eq!(mock_signer.call_count(), 1);
+}
+
+/// Builds a minimal, decodable unsigned Elements transaction
+fn unsigned_elements_tx_hex() -> String {
+    let tx = elements::Transaction {
+        version: 2,
+        lock_time: elements::LockTime::ZERO,
+        input: vec![elements::TxIn::default()],
+        output: vec![elements::TxOut::new_fee(
+            1_000,
+            elements::AssetId::default(),
+        )],
+    };
+    elements::encode::serialize_hex(&tx)
+}
+
+/// Serializes a transaction spending `outpoints` with a dummy witness on each input
+fn signed_tx_spending(outpoints: &[(&str, u32)]) -> String {
+    use std::str::FromStr;
+
+    let input = outpoints
+        .iter()
+        .map(|(txid, vout)| {
+            let mut input = elements::TxIn {
+                previous_output: elements::OutPoint::new(
+                    elements::Txid::from_str(txid).unwrap(),
+                    *vout,
+                ),
+                ..Default::default()
+            };
+            input.witness.script_witness = vec![vec![0x01; 72], vec![0x02; 33]];
+            input
+        })
+        .collect();
+    let tx = elements::Transaction {
+        version: 2,
+        lock_time: elements::LockTime::ZERO,
+        input,
+        output: vec![elements::TxOut::new_fee(
+            1_000,
+            elements::AssetId::default(),
+        )],
+    };
+    elements::encode::serialize_hex(&tx)
+}
+
+#[test]
+fn test_verify_signed_inputs() {
+    let rpc = ElementsRpc::new(
+        "http://localhost:18884".to_string(),
+        "user".to_string(),
+        "pass".to_string(),
+    );
+    let asset_txid = "ab".repeat(32);
+    let fee_txid = "bc".repeat(32);
+    let mut utxos = create_mock_utxos("asset", vec![100.0, 0.0005]);
+    utxos[0].txid = asset_txid.clone();
+    utxos[1].txid = fee_txid.clone();
+    utxos[1].vout = 3;
+
+    let matching = signed_tx_spending(&[(&asset_txid, 0), (&fee_txid, 3)]);
+    rpc.verify_signed_inputs(&matching, &utxos).unwrap();
+
+    let extra_txid = "cd".repeat(32);
+    let extra = signed_tx_spending(&[(&asset_txid, 0), (&fee_txid, 3), (&extra_txid, 1)]);
+    let err = rpc.verify_signed_inputs(&extra, &utxos).unwrap_err();
+    assert!(matches!(err, AmpError::Validation(_)), "{err:?}");
+    assert!(
+        err.to_string()
+            .contains(&format!("unexpected input 2 spends {extra_txid}:1")),
+        "{err}"
+    );
+
+    let reordered = signed_tx_spending(&[(&fee_txid, 3), (&asset_txid, 0)]);
+    let err = rpc.verify_signed_inputs(&reordered, &utxos).unwrap_err();
+    assert!(err.to_string().contains("input 0 spends"), "{err}");
+    assert!(err.to_string().contains("input 1 spends"), "{err}");
+
+    assert!(rpc.verify_signed_inputs("not hex", &utxos).is_err());
+}
+
+#[tokio::test]
+async fn test_dry_signer_sign_and_broadcast() {
+    let server = MockServer::start();
+    let unsigned_tx = unsigned_elements_tx_hex();
+
+    let broadcast_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "sendrawtransaction"}"#);
+        then.status(200).json_body(json!({
+            "jsonrpc": "1.0",
+            "id": "amp-client",
+            "result": "dry_run_txid",
+            "error": null
+        }));
+    });
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let signer = DrySigner::new();
+
+    let txid = rpc
+        .sign_and_broadcast_transaction(&unsigned_tx, &signer)
+        .await
+        .unwrap();
+
+    assert_eq!(txid, "dry_run_txid");
+    assert_eq!(signer.call_count(), 1);
+    assert_eq!(signer.received_transactions(), vec![unsigned_tx.clone()]);
+    broadcast_mock.assert();
+
+    // The broadcast transaction carries the dummy witness on its only input
+    let signed_tx = rpc.sign_transaction(&unsigned_tx, &signer).await.unwrap();
+    let decoded: elements::Transaction =
+        elements::encode::deserialize(&hex::decode(&signed_tx).unwrap()).unwrap();
+    assert_eq!(
+        decoded.input[0].witness.script_witness,
+        vec![DRY_SIGNER_DUMMY_WITNESS.to_vec()]
+    );
+    assert!(signed_tx.len() > unsigned_tx.len());
+}
+
+fn mock_blockchain_chain<'a>(server: &'a MockServer, chain: &str) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "getblockchaininfo"}"#);
+        then.status(200).json_body(json!({
+            "result": {
+                "chain": chain,
+                "blocks": 100,
+                "headers": 100,
+                "bestblockhash": "00".repeat(32)
+            },
+            "error": null,
+            "id": "amp-client"
+        }));
+    })
+}
+
+#[tokio::test]
+async fn test_testnet_signer_rejected_by_mainnet_node() {
+    let server = MockServer::start();
+    let chain_mock = mock_blockchain_chain(&server, "liquidv1");
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+
+    let err = rpc.verify_network_compatibility(&signer).await.unwrap_err();
+    assert!(matches!(err, AmpError::Validation(_)));
+    assert!(err.to_string().contains("testnet"));
+    assert!(err.to_string().contains("liquidv1"));
+
+    // Signing flows stop before the signer is invoked
+    let result = rpc
+        .sign_transaction(&unsigned_elements_tx_hex(), &signer)
+        .await;
+    assert!(matches!(result, Err(AmpError::Validation(_))));
+    chain_mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn test_testnet_signer_accepted_by_test_networks() {
+    for chain in ["liquidtestnet", "elementsregtest"] {
+        let server = MockServer::start();
+        let chain_mock = mock_blockchain_chain(&server, chain);
+        let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+        let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+
+        rpc.verify_network_compatibility(&signer).await.unwrap();
+        chain_mock.assert();
+    }
+
+    // Signers without a network are not checked against the node
+    let server = MockServer::start();
+    let chain_mock = mock_blockchain_chain(&server, "liquidv1");
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    rpc.verify_network_compatibility(&DrySigner::new())
+        .await
+        .unwrap();
+    chain_mock.assert_hits(0);
+}
+
+/// A UTXO paying `address` with a script the test signer cannot derive
+fn foreign_utxo(address: &str) -> Unspent {
+    Unspent {
+        txid: "11".repeat(32),
+        vout: 0,
+        amount: 1.0,
+        asset: DISTRIBUTION_ASSET_ID.to_string(),
+        address: address.to_string(),
+        spendable: true,
+        confirmations: Some(10),
+        scriptpubkey: Some(format!("a914{}87", "22".repeat(20))),
+        redeemscript: None,
+        witnessscript: None,
+        amountblinder: None,
+        assetblinder: None,
+    }
+}
+
+/// Mocks `getaddressinfo` for `address`, reporting `fingerprint` as its
+/// `hdmasterfingerprint` when set
+fn mock_address_origin<'a>(
+    server: &'a MockServer,
+    address: &str,
+    fingerprint: Option<&str>,
+) -> httpmock::Mock<'a> {
+    let mut info = json!({"address": address, "ismine": true, "solvable": true});
+    if let Some(fingerprint) = fingerprint {
+        info["hdmasterfingerprint"] = json!(fingerprint);
+        info["hdkeypath"] = json!("m/49h/1776h/0h/0/57");
+    }
+    server.mock(|when, then| {
+        when.method(POST).json_body_partial(
+            json!({"method": "getaddressinfo", "params": [address]}).to_string(),
+        );
+        then.status(200).json_body(rpc_result(info));
+    })
+}
+
+#[tokio::test]
+async fn test_sign_and_broadcast_rejects_inputs_of_another_master_key() {
     let server = MockServer::start();
+    mock_blockchain_chain(&server, "liquidtestnet");
+    let origin_mock = mock_address_origin(&server, "foreign_address", Some("deadbeef"));
+    let broadcast_mock = server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "sendrawtransaction"}"#);
+        then.status(200)
+            .json_body(rpc_result(json!("33".repeat(32))));
+    });
     let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
 
-    let unsigned_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002";
-    let expected_tx = "different_transaction_hex";
+    let error = rpc
+        .sign_and_broadcast_transaction_with_utxos(
+            &unsigned_elements_tx_hex(),
+            &[foreign_utxo("foreign_address")],
+            &signer,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AmpError::Validation(_)), "{error}");
+    let message = error.to_string();
+    assert!(message.contains("does not own input 0"), "{message}");
+    assert!(message.contains("deadbeef"), "{message}");
+    assert!(message.contains(&signer.fingerprint()), "{message}");
+    origin_mock.assert();
+    broadcast_mock.assert_hits(0);
+}
 
-    let mock_signer = MockSigner::with_expected_input(expected_tx.to_string());
+#[tokio::test]
+async fn test_sign_and_broadcast_defers_to_wallet_past_the_gap_limit() {
+    let server = MockServer::start();
+    mock_blockchain_chain(&server, "liquidtestnet");
+    let signer = LwkSoftwareSigner::new(TEST_MNEMONIC).unwrap();
+    // An input far past OWNERSHIP_GAP_LIMIT that the wallet derives from the
+    // signer's master key, and one the wallet has no key origin for
+    let deep_mock = mock_address_origin(&server, "deep_address", Some(&signer.fingerprint()));
+    let unknown_mock = mock_address_origin(&server, "unknown_address", None);
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
 
-    let result = rpc.sign_transaction(unsigned_tx, &mock_signer).await;
+    let error = rpc
+        .sign_and_broadcast_transaction_with_utxos(
+            "00",
+            &[
+                foreign_utxo("deep_address"),
+                foreign_utxo("unknown_address"),
+            ],
+            &signer,
+        )
+        .await
+        .unwrap_err();
 
-    assert!(result.is_err());
-    let error = result.unwrap_err();
-    assert!(error.to_string().contains("Expected transaction"));
-    assert_eq!(mock_signer.call_count(), 1);
+    // Neither input is rejected; the malformed transaction fails at signing
+    assert!(matches!(error, AmpError::Signer(_)), "{error}");
+    deep_mock.assert();
+    unknown_mock.assert();
 }
 
 #[tokio::test]
-async fn test_signer_integration_with_custom_return_value() {
+async fn test_dry_signer_canned_signed_hex() {
     let server = MockServer::start();
     let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
 
-    let unsigned_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000000000000002";
-    let custom_signed_tx = "0200000000010123456789abcdef1234567890abcdef1234567890abcdef1234567890abcdef00000000deadbeef00000000";
-
-    let mock_signer = MockSigner::with_return_value(custom_signed_tx.to_string());
+    let unsigned_tx = "0200000000010123456789abcdef";
+    let canned = "0200000000010123456789abcdef0000deadbeef";
+    let signer = DrySigner::with_signed_hex(canned);
 
-    let result = rpc.sign_transaction(unsigned_tx, &mock_signer).await;
+    let signed_tx = rpc.sign_transaction(unsigned_tx, &signer).await.unwrap();
+    assert_eq!(signed_tx, canned);
+    assert_eq!(signer.call_count(), 1);
 
-    if result.is_err() {
-        println!("Error: {}", result.as_ref().unwrap_err());
-    }
-    assert!(result.is_ok());
-    let signed_tx = result.unwrap();
-    assert_eq!(signed_tx, custom_signed_tx);
-    assert_eq!(mock_signer.call_count(), 1);
+    // Without a canned value, undecodable input is reported as a signer error
+    let result = rpc.sign_transaction(unsigned_tx, &DrySigner::new()).await;
+    assert!(matches!(result, Err(AmpError::Signer(_))));
 }
 
 #[tokio::test]
@@ -563,7 +2213,7 @@ async fn test_liquid_specific_transaction_format() {
     );
     expected_assets.insert("address_0".to_string(), asset_id.to_string());
 
-    let _expected_inputs = vec![TxInput {
+    let _expected_inputs = [TxInput {
         txid: "txid_000".to_string(),
         vout: 0,
         sequence: None,
@@ -616,7 +2266,7 @@ async fn test_transaction_construction_with_multiple_outputs() {
     expected_assets.insert("recipient2".to_string(), asset_id.to_string());
     expected_assets.insert("address_0".to_string(), asset_id.to_string());
 
-    let _expected_inputs = vec![TxInput {
+    let _expected_inputs = [TxInput {
         txid: "txid_000".to_string(),
         vout: 0,
         sequence: None,
@@ -663,7 +2313,7 @@ async fn test_transaction_construction_no_change_needed() {
     let mut expected_assets = HashMap::new();
     expected_assets.insert("recipient1".to_string(), asset_id.to_string());
 
-    let _expected_inputs = vec![TxInput {
+    let _expected_inputs = [TxInput {
         txid: "txid_000".to_string(),
         vout: 0,
         sequence: None,
@@ -711,7 +2361,7 @@ async fn test_transaction_construction_dust_change_handling() {
     expected_assets.insert("recipient1".to_string(), asset_id.to_string());
     expected_assets.insert("address_0".to_string(), asset_id.to_string());
 
-    let _expected_inputs = vec![TxInput {
+    let _expected_inputs = [TxInput {
         txid: "txid_000".to_string(),
         vout: 0,
         sequence: None,
@@ -767,6 +2417,181 @@ async fn test_transaction_construction_zero_amount_distribution() {
         .contains("Total distribution amount must be greater than zero"));
 }
 
+const TESTNET_LBTC_ASSET_ID: &str =
+    "144c654344aa716d6f3abcc1ca90e5641e4e2a7f633bc09fe3baf64585819a49";
+
+/// Mocks a wallet holding `asset_utxos` plus `lbtc_utxos` of testnet L-BTC,
+/// answering `getnewaddress` with `burn_change_address`
+fn mock_burn_wallet(server: &MockServer, asset_utxos: Vec<Unspent>, lbtc_amounts: Vec<f64>) {
+    let mut utxos = asset_utxos;
+    utxos.extend(
+        create_mock_utxos(TESTNET_LBTC_ASSET_ID, lbtc_amounts)
+            .into_iter()
+            .map(|utxo| Unspent {
+                txid: format!("lbtc_{}", utxo.txid),
+                ..utxo
+            }),
+    );
+    server.mock(|when, then| {
+        when.method(POST).body_contains("getnewaddress");
+        then.status(200).json_body(json!({
+            "result": "burn_change_address",
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST).body_contains("listunspent");
+        then.status(200).json_body(json!({
+            "result": utxos,
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST).body_contains("loadwallet");
+        then.status(200).json_body(json!({
+            "result": {"name": "test_wallet"},
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+}
+
+#[tokio::test]
+async fn test_build_burn_transaction_returns_change_for_non_exact_inputs() {
+    let server = MockServer::start();
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+
+    // 60.0 + 30.0 are both needed to burn 75.0, leaving 15.0 of change; the
+    // L-BTC input pays the 0.00001 minimum fee and returns the rest
+    mock_burn_wallet(
+        &server,
+        create_mock_utxos(asset_id, vec![30.0, 60.0, 5.0]),
+        vec![0.0005],
+    );
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .body_contains("createrawtransaction")
+            .body_contains(r#""burn":"75.00000000""#)
+            .body_contains(r#""burn_change_address":"15.00000000""#)
+            .body_contains(r#""burn_change_address":"0.00049000""#)
+            .body_contains(TESTNET_LBTC_ASSET_ID);
+        then.status(200).json_body(json!({
+            "result": "0200000000010123456789abcdef",
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    let blind_mock = server.mock(|when, then| {
+        when.method(POST)
+            .body_contains("blindrawtransaction")
+            .body_contains("0200000000010123456789abcdef");
+        then.status(200).json_body(json!({
+            "result": "0200000000010123456789abcdef_blinded",
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let (raw_tx, selected_utxos, change_amount) = rpc
+        .build_burn_transaction("test_wallet", asset_id, 75.0)
+        .await
+        .unwrap();
+
+    assert_eq!(raw_tx, "0200000000010123456789abcdef_blinded");
+    assert_eq!(
+        selected_utxos.iter().map(|u| u.amount).collect::<Vec<_>>(),
+        vec![60.0, 30.0, 0.0005]
+    );
+    assert_eq!(change_amount, 15.0);
+    create_mock.assert();
+    blind_mock.assert();
+}
+
+#[tokio::test]
+async fn test_build_burn_transaction_fails_when_blinding_fails() {
+    let server = MockServer::start();
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+    mock_burn_wallet(
+        &server,
+        create_mock_utxos(asset_id, vec![100.0]),
+        vec![0.0005],
+    );
+    server.mock(|when, then| {
+        when.method(POST).body_contains("createrawtransaction");
+        then.status(200).json_body(json!({
+            "result": "0200000000010123456789abcdef",
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST).body_contains("blindrawtransaction");
+        then.status(500).json_body(json!({
+            "result": null,
+            "error": {"code": -8, "message": "Unable to blind transaction"},
+            "id": "amp-client"
+        }));
+    });
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let error = rpc
+        .build_burn_transaction("test_wallet", asset_id, 75.0)
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("Unable to blind"), "{error}");
+}
+
+#[tokio::test]
+async fn test_build_burn_transaction_enforces_max_fee() {
+    let server = MockServer::start();
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+    // Too little above the minimum fee for L-BTC change, so all of it is fee
+    mock_burn_wallet(
+        &server,
+        create_mock_utxos(asset_id, vec![100.0]),
+        vec![0.000015],
+    );
+    let create_mock = server.mock(|when, then| {
+        when.method(POST).body_contains("createrawtransaction");
+        then.status(200).json_body(json!({
+            "result": "0200000000010123456789abcdef",
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string())
+        .with_max_fee_sats(1_000);
+    let error = rpc
+        .build_burn_transaction("test_wallet", asset_id, 75.0)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AmpError::Validation(_)), "{error:?}");
+    assert!(error.to_string().contains("1500 sats"), "{error}");
+    create_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn test_build_burn_transaction_rejects_zero_amount() {
+    let rpc = ElementsRpc::new(
+        "http://localhost:18884".to_string(),
+        "user".to_string(),
+        "pass".to_string(),
+    );
+
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+    let result = rpc
+        .build_burn_transaction("test_wallet", asset_id, 0.0)
+        .await;
+
+    assert!(matches!(result, Err(AmpError::Validation(_))));
+}
+
 #[tokio::test]
 async fn test_signer_validation_comprehensive() {
     let server = MockServer::start();
@@ -886,10 +2711,142 @@ async fn test_utxo_selection_with_confirmations_filter() {
     }
 }
 
+#[tokio::test]
+async fn test_wait_for_spendable_utxo_after_two_polls() {
+    let node = MockElementsRpc::start();
+    let txid = "5a4cb2a3a3b34e1a8f1e06b4c9a9b22ee2a5b1b2d7d0e7f3c4a1f0e9d8c7b6a5";
+    node.set_unspent_confirmations_sequence(txid, 0, &[3]);
+    node.set_unspent_confirmations_sequence(txid, 1, &[0, 1, 2]);
+
+    let config = ConfirmationConfig::default()
+        .with_min_confirmations(2)
+        .with_poll_interval_secs(0);
+
+    let utxo = node
+        .rpc()
+        .wait_for_spendable_utxo("issuer_wallet", txid, 1, &config)
+        .await
+        .unwrap();
+
+    assert_eq!(utxo.txid, txid);
+    assert_eq!(utxo.vout, 1);
+    assert_eq!(utxo.confirmations, Some(2));
+    assert_eq!(node.call_count("listunspent"), 3);
+}
+
+#[tokio::test]
+async fn test_wait_for_spendable_utxo_times_out_for_missing_output() {
+    let node = MockElementsRpc::start();
+    let txid = "5a4cb2a3a3b34e1a8f1e06b4c9a9b22ee2a5b1b2d7d0e7f3c4a1f0e9d8c7b6a5";
+    node.set_unspent_confirmations_sequence(txid, 0, &[3]);
+
+    let config = ConfirmationConfig::default()
+        .with_min_confirmations(1)
+        .with_timeout_minutes(0)
+        .with_poll_interval_secs(1);
+
+    let result = node
+        .rpc()
+        .wait_for_spendable_utxo("issuer_wallet", txid, 1, &config)
+        .await;
+
+    assert!(matches!(result, Err(AmpError::Timeout(_))));
+}
+
+#[tokio::test]
+async fn test_wait_for_spendable_utxo_checks_spendable_and_solvable() {
+    let txid = "5a4cb2a3a3b34e1a8f1e06b4c9a9b22ee2a5b1b2d7d0e7f3c4a1f0e9d8c7b6a5";
+    let config = ConfirmationConfig::default()
+        .with_min_confirmations(1)
+        .with_poll_interval_secs(0);
+    let listed = |spendable: bool, solvable: bool| {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/wallet/issuer_wallet")
+                .json_body_partial(r#"{"method": "listunspent"}"#);
+            then.status(200).json_body(rpc_result(json!([{
+                "txid": txid, "vout": 0, "amount": 1.0, "asset": DISTRIBUTION_ASSET_ID,
+                "address": "mock_address", "spendable": spendable, "solvable": solvable,
+                "confirmations": 1
+            }])));
+        });
+        server
+    };
+
+    // A watch-only output the wallet can solve is spent with an external signer
+    let server = listed(false, true);
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let utxo = rpc
+        .wait_for_spendable_utxo("issuer_wallet", txid, 0, &config)
+        .await
+        .unwrap();
+    assert!(!utxo.spendable);
+
+    // One it can neither spend nor solve fails without waiting for the timeout
+    let server = listed(false, false);
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let error = rpc
+        .wait_for_spendable_utxo("issuer_wallet", txid, 0, &config)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, AmpError::Validation(_)), "{error:?}");
+    assert!(error.to_string().contains("neither spendable nor solvable"));
+}
+
+#[tokio::test]
+async fn test_rpc_call_retries_node_warmup_error() {
+    let node = MockElementsRpc::start();
+    let txid = "1c9a7d5e3b2f4a6c8e0d1b3f5a7c9e2d4f6b8a0c1e3d5f7a9b2c4e6d8f0a1b3c";
+    node.set_confirmations_sequence(txid, &[1]);
+    node.queue_errors("gettransaction", -28, 1);
+
+    let rpc = node
+        .rpc()
+        .with_retry_config(RetryConfig::for_tests().with_base_delay_ms(10));
+    let tx_detail = rpc.get_transaction(txid).await.unwrap();
+
+    assert_eq!(tx_detail.confirmations, 1);
+    assert_eq!(node.call_count("gettransaction"), 2);
+}
+
+#[tokio::test]
+async fn test_rpc_call_does_not_retry_permanent_errors() {
+    let node = MockElementsRpc::start();
+    let txid = "1c9a7d5e3b2f4a6c8e0d1b3f5a7c9e2d4f6b8a0c1e3d5f7a9b2c4e6d8f0a1b3c";
+    node.set_confirmations_sequence(txid, &[1]);
+    node.queue_errors("gettransaction", -8, 1);
+
+    let rpc = node
+        .rpc()
+        .with_retry_config(RetryConfig::for_tests().with_base_delay_ms(10));
+    let error = rpc.get_transaction(txid).await.unwrap_err();
+
+    assert!(
+        error.to_string().contains("-8"),
+        "unexpected error: {error}"
+    );
+    assert_eq!(node.call_count("gettransaction"), 1);
+}
+
+#[tokio::test]
+async fn test_rpc_call_warmup_retries_are_bounded() {
+    let node = MockElementsRpc::start();
+    let txid = "1c9a7d5e3b2f4a6c8e0d1b3f5a7c9e2d4f6b8a0c1e3d5f7a9b2c4e6d8f0a1b3c";
+    node.set_confirmations_sequence(txid, &[1]);
+    node.queue_errors("gettransaction", -28, 5);
+
+    let rpc = node
+        .rpc()
+        .with_retry_config(RetryConfig::for_tests().with_base_delay_ms(10));
+    assert!(rpc.get_transaction(txid).await.is_err());
+    assert_eq!(node.call_count("gettransaction"), 2);
+}
+
 #[tokio::test]
 async fn test_confirmation_polling_success_immediate() {
     let server = MockServer::start();
-    let txid = "test_txid_immediate_confirmation";
+    let txid = "332beb69994435786697ccd4c0082f13cabad1c90e8afb53648e7e23e6944798";
 
     // Mock get_transaction to return transaction with sufficient confirmations immediately
     create_gettransaction_mock(&server, txid, 3, Some(12345));
@@ -899,8 +2856,8 @@ async fn test_confirmation_polling_success_immediate() {
     // Test with 2 required confirmations - should succeed immediately
     let result = rpc.wait_for_confirmations(txid, Some(2), Some(1)).await;
 
-    if result.is_err() {
-        println!("Error: {:?}", result.as_ref().unwrap_err());
+    if let Err(e) = &result {
+        println!("Error: {:?}", e);
     }
     assert!(result.is_ok());
     let tx_detail = result.unwrap();
@@ -912,7 +2869,7 @@ async fn test_confirmation_polling_success_immediate() {
 #[tokio::test]
 async fn test_confirmation_polling_success_after_wait() {
     let server = MockServer::start();
-    let txid = "test_txid_delayed_confirmation";
+    let txid = "3920c2d230aafb9a465af989a37f976d24527b54d769ba419c767705488888b2";
 
     // Mock get_transaction to return sufficient confirmations
     create_gettransaction_mock(&server, txid, 2, Some(12345));
@@ -930,10 +2887,137 @@ async fn test_confirmation_polling_success_after_wait() {
     assert_eq!(tx_detail.confirmations, 2);
 }
 
+#[tokio::test]
+async fn test_confirmation_polling_scripted_progression() {
+    let node = MockElementsRpc::start();
+    let txid = "7790507cacb745838c198e53a5a064f6930fe0570e7a38d2888d6d511db5ef2e";
+    node.set_confirmations_sequence(txid, &[0, 1, 2, 3]);
+
+    let config = ConfirmationConfig::default()
+        .with_min_confirmations(2)
+        .with_poll_interval_secs(0);
+
+    let tx_detail = node
+        .rpc()
+        .wait_for_confirmations_with_config(txid, &config)
+        .await
+        .unwrap();
+
+    // The loop stops as soon as the minimum is reached, leaving the last step unused
+    assert_eq!(tx_detail.txid, txid);
+    assert_eq!(tx_detail.confirmations, 2);
+    assert_eq!(node.call_count("gettransaction"), 3);
+}
+
+/// Mocks the wallet's `listsinceblock` with one entry per `(txid, confirmations)`
+fn mock_listsinceblock<'a>(server: &'a MockServer, entries: &[(&str, u32)]) -> httpmock::Mock<'a> {
+    let entries: Vec<serde_json::Value> = entries
+        .iter()
+        .map(|(txid, confirmations)| {
+            json!({"txid": txid, "category": "send", "amount": -1.0, "confirmations": confirmations})
+        })
+        .collect();
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(r#"{"method": "listsinceblock", "params": ["", 1, true]}"#);
+        then.status(200).json_body(json!({
+            "result": {"transactions": entries, "removed": [], "lastblock": "00".repeat(32)},
+            "error": null,
+            "id": "amp-client"
+        }));
+    })
+}
+
+/// Mocks the wallet's `gettransaction` for `txid`
+fn mock_wallet_gettransaction<'a>(
+    server: &'a MockServer,
+    txid: &str,
+    confirmations: u32,
+) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/wallet/test_wallet")
+            .json_body_partial(format!(
+                r#"{{"method": "gettransaction", "params": ["{txid}", true]}}"#
+            ));
+        then.status(200).json_body(json!({
+            "result": {"txid": txid, "confirmations": confirmations, "hex": "", "details": []},
+            "error": null,
+            "id": "amp-client"
+        }));
+    })
+}
+
+#[tokio::test]
+async fn test_wait_for_confirmations_batch_shares_polls() {
+    let server = MockServer::start();
+    let first = "7790507cacb745838c198e53a5a064f6930fe0570e7a38d2888d6d511db5ef2e".to_string();
+    let second = "332beb69994435786697ccd4c0082f13cabad1c90e8afb53648e7e23e6944798".to_string();
+    let unlisted = "a05600391085910205d7e35748c4909d37d4a3c624710605e38822ec208ab5e3".to_string();
+    let list_mock = mock_listsinceblock(&server, &[(&first, 2), (&first, 2), (&second, 3)]);
+    let first_mock = mock_wallet_gettransaction(&server, &first, 2);
+    let second_mock = mock_wallet_gettransaction(&server, &second, 3);
+    let unlisted_mock = mock_wallet_gettransaction(&server, &unlisted, 4);
+
+    let config = ConfirmationConfig::default()
+        .with_min_confirmations(2)
+        .with_poll_interval_secs(0);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let confirmed = rpc
+        .wait_for_confirmations_batch(
+            "test_wallet",
+            &[first.clone(), second.clone(), unlisted.clone()],
+            &config,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(confirmed.len(), 3);
+    assert_eq!(confirmed[&first].confirmations, 2);
+    assert_eq!(confirmed[&second].confirmations, 3);
+    assert_eq!(confirmed[&unlisted].confirmations, 4);
+    // One listing covers every transaction; each is fetched once, when it
+    // confirms or because the listing does not include it
+    list_mock.assert_hits(1);
+    first_mock.assert_hits(1);
+    second_mock.assert_hits(1);
+    unlisted_mock.assert_hits(1);
+}
+
+#[tokio::test]
+async fn test_wait_for_confirmations_batch_reports_pending_on_timeout() {
+    let server = MockServer::start();
+    let confirmed = "7790507cacb745838c198e53a5a064f6930fe0570e7a38d2888d6d511db5ef2e".to_string();
+    let stuck = "a05600391085910205d7e35748c4909d37d4a3c624710605e38822ec208ab5e3".to_string();
+    mock_listsinceblock(&server, &[(&confirmed, 2), (&stuck, 0)]);
+    mock_wallet_gettransaction(&server, &confirmed, 2);
+    let stuck_mock = mock_wallet_gettransaction(&server, &stuck, 0);
+
+    let config = ConfirmationConfig::default()
+        .with_min_confirmations(2)
+        .with_timeout_minutes(0)
+        .with_poll_interval_secs(1);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let err = rpc
+        .wait_for_confirmations_batch("test_wallet", &[confirmed.clone(), stuck.clone()], &config)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, AmpError::Timeout(_)));
+    assert!(err.to_string().contains("1 of 2 transactions confirmed"));
+    assert!(err.to_string().contains(&stuck));
+    assert!(!err.to_string().contains(&confirmed));
+    // Pending transactions are read from the listing alone
+    stuck_mock.assert_hits(0);
+}
+
 #[tokio::test]
 async fn test_confirmation_polling_timeout() {
     let server = MockServer::start();
-    let txid = "test_txid_timeout";
+    let txid = "a05600391085910205d7e35748c4909d37d4a3c624710605e38822ec208ab5e3";
 
     // Mock get_transaction to always return 0 confirmations
     create_gettransaction_mock(&server, txid, 0, None);
@@ -958,7 +3042,7 @@ async fn test_confirmation_polling_timeout() {
 #[tokio::test]
 async fn test_confirmation_polling_rpc_errors_with_recovery() {
     let server = MockServer::start();
-    let txid = "test_txid_rpc_errors";
+    let txid = "79aca415c9b65a8dd4b037700eb3a195b33cc0dd289b692be56187ab2b6e41eb";
 
     // Mock successful response - the polling logic handles RPC errors by continuing to poll
     create_gettransaction_mock(&server, txid, 3, Some(12345));
@@ -979,7 +3063,7 @@ async fn test_confirmation_polling_rpc_errors_with_recovery() {
 #[tokio::test]
 async fn test_confirmation_polling_default_parameters() {
     let server = MockServer::start();
-    let txid = "test_txid_defaults";
+    let txid = "2403ef769a6d34753795226c5fa24bcf11afa1c26283648493df6a8e3433dc3b";
 
     // Mock get_transaction to return exactly 2 confirmations (default minimum)
     create_gettransaction_mock(&server, txid, 2, Some(12345));
@@ -997,7 +3081,7 @@ async fn test_confirmation_polling_default_parameters() {
 #[tokio::test]
 async fn test_confirmation_polling_custom_minimum_confirmations() {
     let server = MockServer::start();
-    let txid = "test_txid_custom_min";
+    let txid = "1442747d0d65dc9623df28318f40e781990635b560620d2877a66344a5e7a4ee";
 
     // Mock get_transaction to return 5 confirmations
     create_gettransaction_mock(&server, txid, 5, Some(12345));
@@ -1025,7 +3109,7 @@ async fn test_confirmation_polling_custom_minimum_confirmations() {
 async fn test_change_data_collection_success_with_multiple_outputs() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-    let txid = "test_txid_change_multiple";
+    let txid = "ab7140cb6e58cdb8ecc07075c711fde236e6a19d5adedc760d9360294427ae96";
 
     // Mock loadwallet first
     server.mock(|when, then| {
@@ -1122,7 +3206,7 @@ async fn test_change_data_collection_success_with_multiple_outputs() {
 async fn test_change_data_collection_no_change_outputs() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-    let txid = "test_txid_no_change";
+    let txid = "b940502efc079bc90184ba69755fabb9a19fbf293188f08f071aebad13c20be2";
 
     // Mock loadwallet first
     server.mock(|when, then| {
@@ -1198,7 +3282,7 @@ async fn test_change_data_collection_no_change_outputs() {
 async fn test_change_data_collection_filters_unspendable() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-    let txid = "test_txid_unspendable";
+    let txid = "b376f2ee9721d0b84fb1edd4b3f10924e1cafe7766f26f97a569b6a2c97baf0d";
 
     // Mock loadwallet first
     server.mock(|when, then| {
@@ -1263,8 +3347,8 @@ async fn test_change_data_collection_filters_unspendable() {
         .collect_change_data(asset_id, txid, &rpc, "test_wallet")
         .await;
 
-    if result.is_err() {
-        println!("Error: {}", result.as_ref().unwrap_err());
+    if let Err(e) = &result {
+        println!("Error: {}", e);
     }
     assert!(result.is_ok());
     let change_data = result.unwrap();
@@ -1281,7 +3365,7 @@ async fn test_change_data_collection_filters_wrong_asset() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
     let different_asset_id = "different_asset_id_hex_string_here_123456789abcdef";
-    let txid = "test_txid_wrong_asset";
+    let txid = "ef13d79da0dc70a215028f69b5af95400ed1e2fa606a1d31dc6c2b8290e5e5da";
 
     // Mock loadwallet first
     server.mock(|when, then| {
@@ -1360,7 +3444,7 @@ async fn test_change_data_collection_filters_wrong_asset() {
 async fn test_change_data_collection_rpc_error() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-    let txid = "test_txid_rpc_error";
+    let txid = "1434c86c364bbaf2dbae5405dbf79340dedcd4562b78e4e67834d9db9190d36f";
 
     // Mock listunspent to return RPC error
     server.mock(|when, then| {
@@ -1398,7 +3482,7 @@ async fn test_change_data_collection_rpc_error() {
 async fn test_change_data_formatting_for_api() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-    let txid = "test_txid_formatting";
+    let txid = "29f750de3daebf70f1c81c3814399417eb7f3566168efa4d9cc4a1912bdb929e";
 
     // Mock loadwallet first
     server.mock(|when, then| {
@@ -1481,16 +3565,16 @@ async fn test_change_data_formatting_for_api() {
 
     // Test serialization to ensure it matches API expectations
     let serialized = serde_json::to_string(&change_data).unwrap();
-    assert!(serialized.contains(&txid));
+    assert!(serialized.contains(txid));
     assert!(serialized.contains("42.75"));
-    assert!(serialized.contains(&asset_id));
+    assert!(serialized.contains(asset_id));
     assert!(serialized.contains("lq1qq2xvpcvfup5j8zscjq05u2wxxjcyewk7979f9lq"));
 }
 
 #[tokio::test]
 async fn test_confirmation_timeout_error_message_format() {
     let server = MockServer::start();
-    let txid = "test_txid_timeout_message";
+    let txid = "a142bbe336b0a065c06779f55fed8e39fefbb35e1e93666f895db5e6f67bf7e4";
 
     // Mock get_transaction to always return 0 confirmations
     server.mock(|when, then| {
@@ -1540,10 +3624,11 @@ async fn test_confirmation_timeout_error_message_format() {
 }
 
 #[tokio::test]
+#[allow(clippy::vec_init_then_push)]
 async fn test_collect_change_data_integration() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-    let distribution_txid = "abc123def456789abc123def456789abc123def456789abc123def456789abc123de";
+    let distribution_txid = "abc123def456789abc123def456789abc123def456789abc123def456789abc1";
 
     // Create mock UTXOs including change outputs from the distribution transaction
     let mut all_utxos = Vec::new();
@@ -1670,7 +3755,7 @@ async fn test_collect_change_data_integration() {
 async fn test_collect_change_data_no_change_scenario() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-    let distribution_txid = "abc123def456789abc123def456789abc123def456789abc123def456789abc123de";
+    let distribution_txid = "abc123def456789abc123def456789abc123def456789abc123def456789abc1";
 
     // Create mock UTXOs with no change outputs from the distribution transaction
     let all_utxos = vec![
@@ -1729,11 +3814,36 @@ async fn test_collect_change_data_no_change_scenario() {
     assert_eq!(change_utxos.len(), 0);
 }
 
+#[test]
+fn test_verify_change_matches_expected_total() {
+    let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+    let rpc = ElementsRpc::new(
+        "http://localhost:1".to_string(),
+        "user".to_string(),
+        "pass".to_string(),
+    );
+    let mut collected = create_mock_utxos(asset_id, vec![30.0, 20.0]);
+    // Outputs of other assets are ignored
+    collected.extend(create_mock_utxos("other_asset", vec![5.0]));
+
+    assert!(rpc.verify_change(50.0, &collected, asset_id).is_ok());
+    assert!(rpc
+        .verify_change(50.000_000_001, &collected, asset_id)
+        .is_ok());
+    // Dust change is never output, so nothing is collected for it
+    assert!(rpc.verify_change(0.000_001, &[], asset_id).is_ok());
+
+    let error = rpc.verify_change(48.0, &collected, asset_id).unwrap_err();
+    assert!(matches!(error, AmpError::Validation(_)));
+    assert!(error.to_string().contains("Collected change of 50"));
+    assert!(rpc.verify_change(48.0, &[], asset_id).is_err());
+}
+
 #[tokio::test]
 async fn test_collect_change_data_workflow_integration() {
     let server = MockServer::start();
     let asset_id = "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
-    let distribution_txid = "abc123def456789abc123def456789abc123def456789abc123def456789abc123de";
+    let distribution_txid = "abc123def456789abc123def456789abc123def456789abc123def456789abc1";
 
     // Simulate the post-confirmation scenario where we need to collect change data
     let post_confirmation_utxos = vec![
@@ -1800,3 +3910,257 @@ async fn test_collect_change_data_workflow_integration() {
 
     // This change data would then be used in the distribution confirmation API call
 }
+
+/// Mocks the default-wallet calls made by `reissue_asset_to_address`,
+/// returning `signed_tx` from `signrawtransactionwithwallet`
+fn mock_reissuance_node(server: &MockServer, asset_id: &str, destination: &str, signed_tx: &str) {
+    let token_id = "4".repeat(64);
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "listissuances"}"#);
+        then.status(200).json_body(json!({
+            "result": [{
+                "asset": asset_id,
+                "token": token_id,
+                "entropy": "5".repeat(64),
+                "isreissuance": false
+            }],
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "listunspent"}"#);
+        then.status(200).json_body(json!({
+            "result": [{
+                "txid": "6".repeat(64),
+                "vout": 1,
+                "amount": 1.0,
+                "asset": token_id,
+                "address": "token_address",
+                "spendable": true,
+                "assetblinder": "7".repeat(64)
+            }],
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    for (method, result) in [
+        ("getnewaddress", json!("token_change_address")),
+        ("createrawtransaction", json!("00")),
+        (
+            "fundrawtransaction",
+            json!({"hex": "02", "fee": 0.0001, "changepos": 1}),
+        ),
+        ("blindrawtransaction", json!("03")),
+        (
+            "signrawtransactionwithwallet",
+            json!({"hex": signed_tx, "complete": true}),
+        ),
+        ("sendrawtransaction", json!("8".repeat(64))),
+    ] {
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .json_body_partial(json!({ "method": method }).to_string());
+            then.status(200)
+                .json_body(json!({"result": result, "error": null, "id": "amp-client"}));
+        });
+    }
+    server.mock(|when, then| {
+        when.method(POST).path("/").json_body_partial(
+            json!({
+                "method": "rawreissueasset",
+                "params": ["00", [{
+                    "input_index": 0,
+                    "asset_amount": 250.0,
+                    "asset_address": destination,
+                    "asset_blinder": "7".repeat(64),
+                    "entropy": "5".repeat(64)
+                }]]
+            })
+            .to_string(),
+        );
+        then.status(200)
+            .json_body(json!({"result": {"hex": "01"}, "error": null, "id": "amp-client"}));
+    });
+}
+
+/// Builds a transaction whose outputs pay `scripts` in order, then a fee
+fn reissuance_tx_hex(scripts: &[elements::Script]) -> String {
+    let mut output: Vec<elements::TxOut> = scripts
+        .iter()
+        .map(|script| elements::TxOut {
+            script_pubkey: script.clone(),
+            ..elements::TxOut::default()
+        })
+        .collect();
+    output.push(elements::TxOut::new_fee(
+        1_000,
+        elements::AssetId::default(),
+    ));
+    let tx = elements::Transaction {
+        version: 2,
+        lock_time: elements::LockTime::ZERO,
+        input: vec![elements::TxIn::default()],
+        output,
+    };
+    elements::encode::serialize_hex(&tx)
+}
+
+#[tokio::test]
+async fn test_reissue_asset_to_address_pays_destination() {
+    let server = MockServer::start();
+    let asset_id = "3".repeat(64);
+    let params = &elements::AddressParams::ELEMENTS;
+    let destination = elements::Address::p2wsh(&elements::Script::from(vec![0x51]), None, params);
+    let token_change = elements::Address::p2wsh(&elements::Script::from(vec![0x52]), None, params);
+    let signed_tx = reissuance_tx_hex(&[token_change.script_pubkey(), destination.script_pubkey()]);
+    mock_reissuance_node(&server, &asset_id, &destination.to_string(), &signed_tx);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let reissuance = rpc
+        .reissue_asset_to_address(&asset_id, 250.0, &destination.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(reissuance.txid, "8".repeat(64));
+    assert_eq!(reissuance.destination, destination.to_string());
+    assert_eq!(reissuance.vout, 1);
+    assert_eq!(reissuance.amount, 250.0);
+}
+
+#[tokio::test]
+async fn test_reissue_asset_to_address_refuses_tx_missing_destination() {
+    let server = MockServer::start();
+    let asset_id = "3".repeat(64);
+    let params = &elements::AddressParams::ELEMENTS;
+    let destination = elements::Address::p2wsh(&elements::Script::from(vec![0x51]), None, params);
+    let elsewhere = elements::Address::p2wsh(&elements::Script::from(vec![0x53]), None, params);
+    let signed_tx = reissuance_tx_hex(&[elsewhere.script_pubkey()]);
+    mock_reissuance_node(&server, &asset_id, &destination.to_string(), &signed_tx);
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let result = rpc
+        .reissue_asset_to_address(&asset_id, 250.0, &destination.to_string())
+        .await;
+
+    assert!(matches!(result, Err(AmpError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_reissue_asset_to_address_requires_token_asset_blinder() {
+    let server = MockServer::start();
+    let asset_id = "3".repeat(64);
+    let params = &elements::AddressParams::ELEMENTS;
+    let destination = elements::Address::p2wsh(&elements::Script::from(vec![0x51]), None, params);
+    let signed_tx = reissuance_tx_hex(&[destination.script_pubkey()]);
+    // Registered first so it takes precedence over the blinded token output
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "listunspent"}"#);
+        then.status(200).json_body(rpc_result(json!([{
+            "txid": "6".repeat(64), "vout": 1, "amount": 1.0, "asset": "4".repeat(64),
+            "address": "token_address", "spendable": true
+        }])));
+    });
+    mock_reissuance_node(&server, &asset_id, &destination.to_string(), &signed_tx);
+    let reissue_mock = server.mock(|when, then| {
+        when.method(POST)
+            .json_body_partial(r#"{"method": "rawreissueasset"}"#);
+        then.status(200).json_body(rpc_result(json!({"hex": "01"})));
+    });
+
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+    let error = rpc
+        .reissue_asset_to_address(&asset_id, 250.0, &destination.to_string())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AmpError::Validation(_)), "{error:?}");
+    assert!(error.to_string().contains("no asset blinder"), "{error}");
+    reissue_mock.assert_hits(0);
+}
+
+/// Mocks a wallet whose asset has reissuance token `token_id` and whose
+/// confirmed token outputs are `token_utxos`
+fn mock_reissuance_token_wallet(
+    server: &MockServer,
+    asset_id: &str,
+    token_id: &str,
+    token_utxos: &[Unspent],
+) {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "listissuances"}"#);
+        then.status(200).json_body(json!({
+            "result": [{
+                "txid": "4".repeat(64),
+                "vin": 0,
+                "entropy": "5".repeat(64),
+                "asset": asset_id,
+                "assetamount": 1000.0,
+                "token": token_id,
+                "tokenamount": 1.0,
+                "isreissuance": false
+            }],
+            "error": null,
+            "id": "amp-client"
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .json_body_partial(r#"{"method": "listunspent"}"#);
+        then.status(200)
+            .json_body(json!({"result": token_utxos, "error": null, "id": "amp-client"}));
+    });
+}
+
+#[tokio::test]
+async fn test_verify_reissuance_token_preserved() {
+    let asset_id = "3".repeat(64);
+    let token_id = "6".repeat(64);
+    let reissuance_txid = "8".repeat(64);
+    let mut token_utxos = create_mock_utxos(&token_id, vec![1.0]);
+    token_utxos[0].txid.clone_from(&reissuance_txid);
+
+    let server = MockServer::start();
+    mock_reissuance_token_wallet(&server, &asset_id, &token_id, &token_utxos);
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    rpc.verify_reissuance_token_preserved(&reissuance_txid, &asset_id)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_verify_reissuance_token_preserved_rejects_missing_token_output() {
+    let asset_id = "3".repeat(64);
+    let token_id = "6".repeat(64);
+    let reissuance_txid = "8".repeat(64);
+    // The wallet still holds a token output, but from an earlier transaction
+    let mut token_utxos = create_mock_utxos(&token_id, vec![1.0]);
+    token_utxos[0].txid = "4".repeat(64);
+
+    let server = MockServer::start();
+    mock_reissuance_token_wallet(&server, &asset_id, &token_id, &token_utxos);
+    let rpc = ElementsRpc::new(server.url("/"), "user".to_string(), "pass".to_string());
+
+    let result = rpc
+        .verify_reissuance_token_preserved(&reissuance_txid, &asset_id)
+        .await;
+
+    match result {
+        Err(AmpError::Validation(message)) => {
+            assert!(message.contains(&token_id));
+            assert!(message.contains("destroyed"));
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+}